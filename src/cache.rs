@@ -0,0 +1,220 @@
+use crate::config::Config;
+use crate::system::report::{
+    BatteryInfo, CpuInfo, DesktopInfo, DiskInfo, DiskUsageInfo, EntryValue, MemoryInfo,
+    NetworkInfo, PackageManagerInfo,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long the value of an entry stays valid, along with the files and environment variables
+/// whose change invalidates it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachePolicy {
+    pub ttl: Duration,
+    pub watched: Vec<PathBuf>,
+    pub env: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "EntryValue", tag = "kind", content = "value")]
+enum EntryValueDef {
+    Text(String),
+    Duration(u64),
+    Packages(Vec<PackageManagerInfo>),
+    Desktop(DesktopInfo),
+    Cpu(CpuInfo),
+    Gpus(Vec<String>),
+    Memory(MemoryInfo),
+    Network(NetworkInfo),
+    Disks(Vec<DiskInfo>),
+    DiskUsage(DiskUsageInfo),
+    Battery(BatteryInfo),
+    TimedOut,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// Unix timestamp, in seconds.
+    stored_at: u64,
+    /// The watched files along with their modification time in milliseconds, if they exist.
+    watched: Vec<(PathBuf, Option<u64>)>,
+    env: Vec<(String, Option<String>)>,
+    #[serde(with = "EntryValueDef")]
+    value: EntryValue,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct CacheFile {
+    /// Some values depend on the disabled entries (e.g. `desktop-version`), any change to them
+    /// discards the cache.
+    config_hash: u64,
+    /// The root the values have been read from, see [`crate::system::environment::SYSROOT_ENV`].
+    #[serde(default)]
+    sysroot: PathBuf,
+    entries: HashMap<String, CacheEntry>,
+}
+
+/// The values of slow entries, kept in `$XDG_CACHE_HOME/afetch/cache.json` between runs.
+pub struct Cache {
+    path: Option<PathBuf>,
+    /// When set, cached values are ignored but fresh ones are still stored.
+    refresh: bool,
+    file: Mutex<CacheFile>,
+    dirty: Mutex<bool>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn modified(path: &Path) -> Option<u64> {
+    let modified: SystemTime = std::fs::metadata(path).ok()?.modified().ok()?;
+    modified
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|duration| u64::try_from(duration.as_millis()).ok())
+}
+
+fn watched_state(watched: &[PathBuf]) -> Vec<(PathBuf, Option<u64>)> {
+    watched
+        .iter()
+        .map(|path| (path.clone(), modified(path)))
+        .collect()
+}
+
+fn env_state(env: &[String]) -> Vec<(String, Option<String>)> {
+    env.iter()
+        .map(|name| (name.clone(), std::env::var(name).ok()))
+        .collect()
+}
+
+fn config_hash(config: &Config) -> u64 {
+    let mut disabled_entries: Vec<&str> = config
+        .disabled_entries
+        .iter()
+        .map(|id| id.as_str())
+        .collect();
+    disabled_entries.sort_unstable();
+    disabled_entries.dedup();
+    let mut hasher: DefaultHasher = DefaultHasher::new();
+    disabled_entries.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl Cache {
+    pub fn path() -> Option<PathBuf> {
+        dirs::cache_dir().map(|cache_dir| cache_dir.join("afetch").join("cache.json"))
+    }
+
+    /// A cache that never returns nor stores anything.
+    pub fn disabled() -> Self {
+        Self {
+            path: None,
+            refresh: false,
+            file: Mutex::new(CacheFile::default()),
+            dirty: Mutex::new(false),
+        }
+    }
+
+    /// Reads the cache file, a missing or unreadable file giving an empty cache. The values read
+    /// from another `sysroot` are discarded.
+    pub fn load(sysroot: &Path, config: &Config, refresh: bool) -> Self {
+        Self::load_from(Self::path(), sysroot, config, refresh)
+    }
+
+    /// Reads the cache file at `path` like [`Cache::load`], the cache being disabled without
+    /// one.
+    pub fn load_from(
+        path: Option<PathBuf>,
+        sysroot: &Path,
+        config: &Config,
+        refresh: bool,
+    ) -> Self {
+        let config_hash: u64 = config_hash(config);
+        let file: CacheFile = path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| file.config_hash == config_hash && file.sysroot == sysroot)
+            .unwrap_or_else(|| CacheFile {
+                config_hash,
+                sysroot: sysroot.to_path_buf(),
+                entries: HashMap::new(),
+            });
+
+        Self {
+            path,
+            refresh,
+            file: Mutex::new(file),
+            dirty: Mutex::new(false),
+        }
+    }
+
+    /// The cached value of an entry, if it is younger than the TTL and none of the watched files
+    /// has changed since it was stored.
+    pub fn get(&self, id: &str, policy: &CachePolicy) -> Option<EntryValue> {
+        if self.path.is_none() || self.refresh {
+            return None;
+        }
+        let file = self.file.lock().ok()?;
+        let entry: &CacheEntry = file.entries.get(id)?;
+        let age: u64 = now().saturating_sub(entry.stored_at);
+        if Duration::from_secs(age) >= policy.ttl
+            || entry.watched != watched_state(&policy.watched)
+            || entry.env != env_state(&policy.env)
+        {
+            return None;
+        }
+        Some(entry.value.clone())
+    }
+
+    pub fn insert(&self, id: &str, policy: &CachePolicy, value: &EntryValue) {
+        if self.path.is_none() {
+            return;
+        }
+        if let (Ok(mut file), Ok(mut dirty)) = (self.file.lock(), self.dirty.lock()) {
+            file.entries.insert(
+                id.to_owned(),
+                CacheEntry {
+                    stored_at: now(),
+                    watched: watched_state(&policy.watched),
+                    env: env_state(&policy.env),
+                    value: value.clone(),
+                },
+            );
+            *dirty = true;
+        }
+    }
+
+    /// Writes the cache file if new values have been stored, failures are silently ignored as
+    /// the cache is only an optimization.
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if !self.dirty.lock().is_ok_and(|dirty| *dirty) {
+            return;
+        }
+        let Ok(file) = self.file.lock() else {
+            return;
+        };
+        let Ok(content) = serde_json::to_string(&*file) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+        let temporary_path: PathBuf = path.with_extension("json.tmp");
+        if std::fs::write(&temporary_path, content).is_ok() {
+            std::fs::rename(&temporary_path, path).ok();
+        }
+    }
+}
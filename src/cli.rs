@@ -0,0 +1,276 @@
+use crate::config::check::{is_disableable, with_suggestion, Diagnostic, EXTRA_DISABLEABLE};
+use crate::config::{Config, EntryId, Language, LayoutItem, LogoStatus};
+use crate::entries::Registry;
+use crate::logos;
+use std::fmt;
+use std::path::PathBuf;
+
+pub const HELP: &str = "A CLI system information tool written in Rust.
+
+Usage: afetch [OPTIONS] [COMMAND]
+
+Commands:
+  config check               Check the configuration and report its problems
+  lang missing [<code>]      List the keys a translation is missing, all of them by default
+  logos list                 List the logos `--logo` accepts, with their other names
+  logos show <name>|--all    Print a logo and its small variant, or every logo
+
+Options:
+  -h, --help                 Print this help and exit
+  -V, --version              Print the version and exit
+  -c, --config <path>        Use this configuration file instead of the user one
+  -p, --profile <name>       Apply a profile defined in the configuration
+      --json                 Print the gathered information as JSON
+      --refresh              Ignore the cached values and gather everything again
+      --timings              Print the time spent on each entry and external command
+      --no-logo              Do not print the logo
+      --logo <name>          Print the logo of another distribution
+      --language <code>      Language to use (en, fr, ...), overrides the configuration
+      --disable <entry,...>  Entries to hide, in addition to the configured ones
+      --only <entry,...>     Only print these entries";
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    ConfigCheck,
+    /// Lists the keys missing from a translation, or from every available one.
+    LangMissing(Option<String>),
+    LogosList,
+    /// Prints a logo, or every logo with `--all`.
+    LogosShow(Option<String>),
+}
+
+/// The options given on the command line, they take precedence over the configuration file.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Cli {
+    pub command: Option<Command>,
+    pub help: bool,
+    pub version: bool,
+    pub json: bool,
+    pub refresh: bool,
+    pub timings: bool,
+    pub config: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub no_logo: bool,
+    /// Set by `--all`, only accepted by `logos show`.
+    pub all: bool,
+    pub logo: Option<String>,
+    pub language: Option<Language>,
+    pub disable: Vec<EntryId>,
+    pub only: Option<Vec<EntryId>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CliError {
+    UnknownFlag(String),
+    MissingValue(String),
+    UnexpectedValue(String),
+    UnexpectedArgument(String),
+    UnknownCommand(String),
+    InvalidValue(String, String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFlag(flag) => write!(f, "unknown flag `{}`", flag),
+            Self::MissingValue(flag) => write!(f, "`{}` expects a value", flag),
+            Self::UnexpectedValue(flag) => write!(f, "`{}` does not take a value", flag),
+            Self::UnexpectedArgument(argument) => write!(f, "unexpected argument `{}`", argument),
+            Self::UnknownCommand(command) => write!(
+                f,
+                "unknown command `{}`, the available commands are `config check`, `lang missing`, `logos list` and `logos show`",
+                command
+            ),
+            Self::InvalidValue(flag, message) => write!(f, "`{}`: {}", flag, message),
+        }
+    }
+}
+
+fn split_list(value: &str) -> Vec<EntryId> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(EntryId::from)
+        .collect()
+}
+
+impl Cli {
+    /// Parses the arguments, without the program name. Values can be given either as the
+    /// next argument (`--logo arch`) or inline (`--logo=arch`).
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, CliError> {
+        let mut cli: Self = Self::default();
+        let mut args = args.into_iter();
+        let mut positionals: Vec<String> = Vec::new();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value): (&str, Option<String>) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_owned())),
+                _ => (arg.as_str(), None),
+            };
+
+            let takes_value: bool = matches!(
+                flag,
+                "-c" | "--config"
+                    | "-p"
+                    | "--profile"
+                    | "--logo"
+                    | "--language"
+                    | "--disable"
+                    | "--only"
+            );
+            if !takes_value {
+                if inline_value.is_some() {
+                    return Err(CliError::UnexpectedValue(flag.to_owned()));
+                }
+                match flag {
+                    "-h" | "--help" => cli.help = true,
+                    "-V" | "--version" => cli.version = true,
+                    "--json" => cli.json = true,
+                    "--refresh" => cli.refresh = true,
+                    "--timings" => cli.timings = true,
+                    "--no-logo" => cli.no_logo = true,
+                    "--all" => cli.all = true,
+                    _ if flag.starts_with('-') => {
+                        return Err(CliError::UnknownFlag(flag.to_owned()))
+                    }
+                    _ => positionals.push(arg.clone()),
+                }
+                continue;
+            }
+
+            let value: String = match inline_value.or_else(|| args.next()) {
+                Some(value) => value,
+                None => return Err(CliError::MissingValue(flag.to_owned())),
+            };
+            match flag {
+                "-c" | "--config" => cli.config = Some(PathBuf::from(value)),
+                "-p" | "--profile" => cli.profile = Some(value),
+                "--logo" => cli.logo = Some(value.to_lowercase()),
+                "--language" => {
+                    cli.language = Some(
+                        value
+                            .parse()
+                            .map_err(|message| CliError::InvalidValue(flag.to_owned(), message))?,
+                    );
+                }
+                "--disable" => cli.disable.extend(split_list(&value)),
+                _ => cli
+                    .only
+                    .get_or_insert_with(Vec::new)
+                    .extend(split_list(&value)),
+            }
+        }
+
+        cli.command = match positionals.as_slice() {
+            [] => None,
+            [config, check] if config == "config" && check == "check" => Some(Command::ConfigCheck),
+            [lang, missing] if lang == "lang" && missing == "missing" => {
+                Some(Command::LangMissing(None))
+            }
+            [lang, missing, code] if lang == "lang" && missing == "missing" => {
+                Some(Command::LangMissing(Some(code.clone())))
+            }
+            [logos, list] if logos == "logos" && list == "list" => Some(Command::LogosList),
+            [logos, show] if logos == "logos" && show == "show" && cli.all => {
+                Some(Command::LogosShow(None))
+            }
+            [logos, show] if logos == "logos" && show == "show" => {
+                return Err(CliError::MissingValue("logos show".to_owned()))
+            }
+            [logos, show, name] if logos == "logos" && show == "show" => {
+                Some(Command::LogosShow(Some(name.to_lowercase())))
+            }
+            [command, ..] if ["config", "lang", "logos"].contains(&command.as_str()) => {
+                return Err(CliError::UnknownCommand(positionals.join(" ")))
+            }
+            [argument, ..] => return Err(CliError::UnexpectedArgument(argument.clone())),
+        };
+
+        if cli.all && cli.command != Some(Command::LogosShow(None)) {
+            return Err(CliError::UnexpectedArgument("--all".to_owned()));
+        }
+
+        Ok(cli)
+    }
+
+    /// Checks that the logo and entries given on the command line exist, the logo files being
+    /// looked for in `logo_dirs`.
+    pub fn validate(&self, registry: &Registry, logo_dirs: &[PathBuf]) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let entry_ids = || registry.entries().iter().map(|entry| entry.id.as_str());
+
+        if let Some(logo) = &self.logo {
+            match logos::get_logo_in(logo, logo_dirs) {
+                Ok(Some(_)) => {}
+                Ok(None) => diagnostics.push(Diagnostic::new(
+                    "--logo",
+                    with_suggestion(
+                        format!("there is no logo named `{}`", logo),
+                        &logos::normalize_name(logo),
+                        logos::logo_names(logo_dirs).iter().map(String::as_str),
+                    ),
+                )),
+                Err(error) => diagnostics.push(Diagnostic::new("--logo", error)),
+            }
+        }
+
+        for id in &self.disable {
+            if !is_disableable(id, registry) {
+                diagnostics.push(Diagnostic::new(
+                    "--disable",
+                    with_suggestion(
+                        format!("there is no entry named `{}`", id),
+                        id.as_str(),
+                        entry_ids().chain(EXTRA_DISABLEABLE),
+                    ),
+                ));
+            }
+        }
+        for id in self.only.iter().flatten() {
+            if registry.get(id.as_str()).is_none() {
+                diagnostics.push(Diagnostic::new(
+                    "--only",
+                    with_suggestion(
+                        format!("there is no entry named `{}`", id),
+                        id.as_str(),
+                        entry_ids(),
+                    ),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Overrides the configuration with the given flags.
+    pub fn apply(&self, config: &mut Config, registry: &Registry) {
+        if let Some(language) = &self.language {
+            config.language = language.clone();
+        }
+        if self.no_logo {
+            config.logo.status = LogoStatus::Disable;
+        }
+
+        if let Some(only) = &self.only {
+            config.entries = Some(
+                registry
+                    .layout(config)
+                    .into_iter()
+                    .filter(|item| match item {
+                        LayoutItem::Entry(id) => only.contains(id),
+                        _ => true,
+                    })
+                    .collect(),
+            );
+        }
+        for id in &self.disable {
+            if !config.disabled_entries.contains(id) {
+                config.disabled_entries.push(id.clone());
+            }
+            if let Some(entries) = &mut config.entries {
+                entries.retain(|item| item != &LayoutItem::Entry(id.clone()));
+            }
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use crate::config::{Config, EntryId, LayoutItem, LogoStatus};
+use crate::entries::Registry;
+use crate::logos::{placeholder_index, PLACEHOLDERS};
+use crate::template::Template;
+use crate::utils::closest_match;
+use serde_yaml::{Mapping, Value};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Entry names that can be disabled without being entries of the registry.
+pub const EXTRA_DISABLEABLE: [&str; 3] = ["cpu-usage", "desktop-version", "color-blocks"];
+const LAYOUT_KEYWORDS: [&str; 4] = ["title", "separator", "spacer", "color-blocks"];
+const MAX_TIMEOUT_MS: u64 = 60_000;
+const MAX_PICTURE_SIZE: usize = 500;
+
+/// Whether `id` names an entry of the registry or one of [`EXTRA_DISABLEABLE`].
+pub fn is_disableable(id: &EntryId, registry: &Registry) -> bool {
+    registry.get(id.as_str()).is_some() || EXTRA_DISABLEABLE.contains(&id.as_str())
+}
+
+/// A problem found in the configuration, `path` being the key it concerns, such as
+/// `disabled_entries[2]` or `logo.status`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagnostic {
+    pub path: String,
+    pub message: String,
+    /// The configuration file the faulty value comes from, when it comes from a file.
+    pub file: Option<PathBuf>,
+    /// 1-based line and column of the faulty value in `file`.
+    pub location: Option<(usize, usize)>,
+}
+
+impl Diagnostic {
+    pub fn new(path: &str, message: String) -> Self {
+        Self {
+            path: path.to_owned(),
+            message,
+            file: None,
+            location: None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.location) {
+            (Some(file), Some((line, column))) => {
+                write!(f, "{}, line {}, column {}: ", file.display(), line, column)?;
+            }
+            (Some(file), None) => write!(f, "{}: ", file.display())?,
+            (None, Some((line, column))) => write!(f, "line {}, column {}: ", line, column)?,
+            (None, None) => {}
+        }
+        if !self.path.is_empty() {
+            write!(f, "`{}`: ", self.path)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Appends `, did you mean `x`?` to a message when one of the candidates is close to `value`.
+pub fn with_suggestion<'a, I: IntoIterator<Item = &'a str>>(
+    message: String,
+    value: &str,
+    candidates: I,
+) -> String {
+    match closest_match(value, candidates) {
+        Some(suggestion) => format!("{}, did you mean `{}`?", message, suggestion),
+        None => message,
+    }
+}
+
+/// Parses one configuration file, checking the type of each key it sets, those of its profiles
+/// included. The keys whose value is invalid are reported and left out of the returned value,
+/// so that the rest of the configuration can still be checked.
+pub fn parse_file(file: &Path, source: &str) -> (Value, Vec<Diagnostic>) {
+    let file_diagnostic = |message: String, location: Option<(usize, usize)>| Diagnostic {
+        path: String::default(),
+        message,
+        file: Some(file.to_path_buf()),
+        location,
+    };
+
+    let mut value: Value = match serde_yaml::from_str(source) {
+        Ok(Value::Null) => return (Value::Mapping(Mapping::new()), Vec::new()),
+        Ok(value @ Value::Mapping(_)) => value,
+        Ok(_) => {
+            return (
+                Value::Mapping(Mapping::new()),
+                vec![file_diagnostic(
+                    "expected a mapping of configuration keys".to_owned(),
+                    Some((1, 1)),
+                )],
+            )
+        }
+        Err(error) => {
+            let location: Option<(usize, usize)> = error
+                .location()
+                .map(|location| (location.line(), location.column()));
+            return (
+                Value::Mapping(Mapping::new()),
+                vec![file_diagnostic(split_error(&error).1, location)],
+            );
+        }
+    };
+
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let profiles: Option<Value> = value
+        .as_mapping_mut()
+        .and_then(|mapping| mapping.remove("profiles"));
+    check_layer(&mut value, "", &mut diagnostics);
+    if let Some(mut profiles) = profiles {
+        match profiles.as_mapping_mut() {
+            Some(profiles) => {
+                for (name, profile) in profiles.iter_mut() {
+                    let path: String = format!("profiles.{}", name.as_str().unwrap_or_default());
+                    if profile.is_null() {
+                        *profile = Value::Mapping(Mapping::new());
+                    } else if profile.is_mapping() {
+                        check_layer(profile, &path, &mut diagnostics);
+                    } else {
+                        diagnostics.push(Diagnostic::new(
+                            &path,
+                            "expected a mapping of configuration keys".to_owned(),
+                        ));
+                        *profile = Value::Mapping(Mapping::new());
+                    }
+                }
+            }
+            None => {
+                diagnostics.push(Diagnostic::new(
+                    "profiles",
+                    "expected a mapping of profile names to configuration keys".to_owned(),
+                ));
+                profiles = Value::Mapping(Mapping::new());
+            }
+        }
+        if let Some(mapping) = value.as_mapping_mut() {
+            mapping.insert(Value::String("profiles".to_owned()), profiles);
+        }
+    }
+
+    for diagnostic in &mut diagnostics {
+        diagnostic.file = Some(file.to_path_buf());
+        diagnostic.location = locate(source, &diagnostic.path).0;
+    }
+    diagnostics.sort_by_key(|diagnostic| diagnostic.location);
+    (value, diagnostics)
+}
+
+/// Deserializes `layer` as a configuration over and over, reporting the invalid key serde
+/// stops at and removing it each time, until the rest is valid. The paths of the diagnostics
+/// start with `prefix`.
+fn check_layer(layer: &mut Value, prefix: &str, diagnostics: &mut Vec<Diagnostic>) {
+    loop {
+        // The paths of the errors are only tracked when parsing text.
+        let source: String = serde_yaml::to_string(layer).unwrap_or_default();
+        let Err(error) = serde_yaml::from_str::<Config>(&source) else {
+            return;
+        };
+        let (mut path, message): (String, String) = split_error(&error);
+        if let Some(location) = error.location() {
+            path = narrow_path(&source, layer, path, location.line());
+        }
+        let full_path: String = [prefix, &path]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<&str>>()
+            .join(".");
+        diagnostics.push(Diagnostic::new(&full_path, message));
+        if !remove_key(layer, &path) {
+            return;
+        }
+    }
+}
+
+/// The path of the key a serde error is about, and its message. An unknown key is reported
+/// along with the closest known one.
+fn split_error(error: &serde_yaml::Error) -> (String, String) {
+    let mut message: String = error.to_string();
+    if let Some(index) = message.rfind(" at line ") {
+        message.truncate(index);
+    }
+    // The messages of serde start with words, the path never contains a space.
+    let (mut path, message): (String, String) = match message.split_once(": ") {
+        Some((path, rest)) if !path.contains(' ') => (path.to_owned(), rest.to_owned()),
+        _ => (String::default(), message),
+    };
+
+    let Some((field, expected)) = message
+        .strip_prefix("unknown field `")
+        .and_then(|rest| rest.split_once('`'))
+    else {
+        return (path, message);
+    };
+    // `expected one of `a`, `b``, `expected `a` or `b`` or `there are no fields`.
+    let candidates: Vec<&str> = expected.split('`').skip(1).step_by(2).collect();
+    let message: String = with_suggestion(
+        format!("there is no key named `{}`", field),
+        field,
+        candidates,
+    );
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(field);
+    (path, message)
+}
+
+/// The errors raised while converting a value, such as an unknown keyword, are given the path
+/// of the mapping holding it but the line of its key, `source` being `layer` written as YAML.
+/// Returns the path of the key of that mapping on `line`, or `path` when it is already the path
+/// of the value on `line`.
+fn narrow_path(source: &str, layer: &Value, path: String, line: usize) -> String {
+    let line_of = |path: &str| locate(source, path).0.map(|(line, _)| line);
+    if !path.is_empty() && line_of(&path) == Some(line) {
+        return path;
+    }
+    let mut parent: Option<&Value> = Some(layer);
+    for segment in parse_path(&path) {
+        parent = match segment {
+            Segment::Key(key) => parent.and_then(|parent| parent.get(key)),
+            Segment::Index(index) => parent.and_then(|parent| parent.get(index)),
+        };
+    }
+    let children = parent
+        .and_then(Value::as_mapping)
+        .into_iter()
+        .flat_map(|mapping| mapping.keys().filter_map(Value::as_str))
+        .map(|key| {
+            if path.is_empty() {
+                key.to_owned()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        });
+    for child in children {
+        if line_of(&child) == Some(line) {
+            return child;
+        }
+    }
+    path
+}
+
+/// Removes the last key of `path` from `value`, along with its value. Returns whether it was
+/// found.
+fn remove_key(value: &mut Value, path: &str) -> bool {
+    let segments: Vec<Segment> = parse_path(path);
+    let Some(last_key) = segments
+        .iter()
+        .rposition(|segment| matches!(segment, Segment::Key(_)))
+    else {
+        return false;
+    };
+    let mut parent: &mut Value = value;
+    for segment in &segments[..last_key] {
+        let child: Option<&mut Value> = match segment {
+            Segment::Key(key) => parent.get_mut(*key),
+            Segment::Index(index) => parent.get_mut(*index),
+        };
+        match child {
+            Some(child) => parent = child,
+            None => return false,
+        }
+    }
+    let Segment::Key(key) = segments[last_key] else {
+        return false;
+    };
+    parent
+        .as_mapping_mut()
+        .and_then(|mapping| mapping.remove(key))
+        .is_some()
+}
+
+fn check_color(diagnostics: &mut Vec<Diagnostic>, path: &str, color: &[u8]) {
+    if color.len() != 3 {
+        diagnostics.push(Diagnostic::new(
+            path,
+            format!(
+                "expected 3 values (red, green and blue), found {}",
+                color.len()
+            ),
+        ));
+    }
+}
+
+fn check_timeout(diagnostics: &mut Vec<Diagnostic>, path: &str, timeout_ms: u64) {
+    if !(1..=MAX_TIMEOUT_MS).contains(&timeout_ms) {
+        diagnostics.push(Diagnostic::new(
+            path,
+            format!(
+                "expected a value between 1 and {}, found {}",
+                MAX_TIMEOUT_MS, timeout_ms
+            ),
+        ));
+    }
+}
+
+/// The semantic checks that cannot be expressed with serde: names, ranges and lengths.
+pub fn validate(config: &Config, registry: &Registry) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let entry_ids = || registry.entries().iter().map(|entry| entry.id.as_str());
+
+    if config.logo.status == LogoStatus::Enable
+        && config.logo.char_type.is_picture()
+        && !Path::new(&config.logo.picture_path).is_file()
+    {
+        diagnostics.push(Diagnostic::new(
+            "logo.picture_path",
+            format!("the file `{}` does not exist", config.logo.picture_path),
+        ));
+    }
+
+    let picture_sizes = [
+        ("logo.picture_width", Some(config.logo.picture_width)),
+        ("logo.picture_height", config.logo.picture_height),
+    ];
+    for (path, size) in picture_sizes {
+        if let Some(size) = size.filter(|size| !(1..=MAX_PICTURE_SIZE).contains(size)) {
+            diagnostics.push(Diagnostic::new(
+                path,
+                format!(
+                    "expected a value between 1 and {}, found {}",
+                    MAX_PICTURE_SIZE, size
+                ),
+            ));
+        }
+    }
+
+    for name in config.logo.colors.keys() {
+        if placeholder_index(name).is_none() {
+            diagnostics.push(Diagnostic::new(
+                &format!("logo.colors.{}", name),
+                format!(
+                    "there is no placeholder named `{}`, expected `c1` to `c{}`",
+                    name, PLACEHOLDERS
+                ),
+            ));
+        }
+    }
+
+    check_color(&mut diagnostics, "text_color", &config.text_color);
+    if let Some(text_color_header) = &config.text_color_header {
+        check_color(&mut diagnostics, "text_color_header", text_color_header);
+    }
+
+    for (i, id) in config.disabled_entries.iter().enumerate() {
+        if !is_disableable(id, registry) {
+            diagnostics.push(Diagnostic::new(
+                &format!("disabled_entries[{}]", i),
+                with_suggestion(
+                    format!("there is no entry named `{}`", id),
+                    id.as_str(),
+                    entry_ids().chain(EXTRA_DISABLEABLE),
+                ),
+            ));
+        }
+    }
+
+    for (i, item) in config.entries.iter().flatten().enumerate() {
+        if let LayoutItem::Entry(id) = item {
+            if registry.get(id.as_str()).is_none() {
+                diagnostics.push(Diagnostic::new(
+                    &format!("entries[{}]", i),
+                    with_suggestion(
+                        format!("there is no entry named `{}`", id),
+                        id.as_str(),
+                        entry_ids().chain(LAYOUT_KEYWORDS),
+                    ),
+                ));
+            }
+        }
+    }
+
+    let builtin: Registry = Registry::builtin();
+    let mut custom_ids: HashSet<String> = HashSet::new();
+    for (i, custom) in config.custom.iter().enumerate() {
+        if custom.command.trim().is_empty() {
+            diagnostics.push(Diagnostic::new(
+                &format!("custom[{}].command", i),
+                "the command is empty".to_owned(),
+            ));
+        }
+        if let Some(timeout_ms) = custom.timeout_ms {
+            check_timeout(
+                &mut diagnostics,
+                &format!("custom[{}].timeout_ms", i),
+                timeout_ms,
+            );
+        }
+        if builtin.get(&custom.id()).is_some() {
+            diagnostics.push(Diagnostic::new(
+                &format!("custom[{}]", i),
+                format!(
+                    "the builtin entry `{}` has the same id, set another `id`",
+                    custom.id()
+                ),
+            ));
+        } else if !custom_ids.insert(custom.id()) {
+            diagnostics.push(Diagnostic::new(
+                &format!("custom[{}]", i),
+                format!("another custom entry is already named `{}`", custom.id()),
+            ));
+        }
+    }
+
+    let mut formats: Vec<(&String, &String)> = config.formats.iter().collect();
+    formats.sort();
+    for (id, format) in formats {
+        let path: String = format!("formats.{}", id);
+        match registry.get(id) {
+            Some(entry) => {
+                if let Err(error) = Template::parse_with_placeholders(format, &entry.placeholders) {
+                    diagnostics.push(Diagnostic::new(&path, error.to_string()));
+                }
+            }
+            None => diagnostics.push(Diagnostic::new(
+                &path,
+                with_suggestion(format!("there is no entry named `{}`", id), id, entry_ids()),
+            )),
+        }
+    }
+
+    check_timeout(
+        &mut diagnostics,
+        "timeout.global_ms",
+        config.timeout.global_ms,
+    );
+    let mut timeouts: Vec<(&String, &u64)> = config.timeout.entries.iter().collect();
+    timeouts.sort();
+    for (id, timeout_ms) in timeouts {
+        let path: String = format!("timeout.entries.{}", id);
+        if registry.get(id).is_none() {
+            diagnostics.push(Diagnostic::new(
+                &path,
+                with_suggestion(format!("there is no entry named `{}`", id), id, entry_ids()),
+            ));
+        }
+        check_timeout(&mut diagnostics, &path, *timeout_ms);
+    }
+
+    let mut ttl_ids: Vec<&String> = config.cache.ttl.keys().collect();
+    ttl_ids.sort();
+    for id in ttl_ids {
+        if registry.get(id).is_none() {
+            diagnostics.push(Diagnostic::new(
+                &format!("cache.ttl.{}", id),
+                with_suggestion(format!("there is no entry named `{}`", id), id, entry_ids()),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+fn parse_path(path: &str) -> Vec<Segment<'_>> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for part in path.split('.') {
+        let (key, indexes): (&str, &str) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if !key.is_empty() {
+            segments.push(Segment::Key(key));
+        }
+        segments.extend(
+            indexes
+                .split(['[', ']'])
+                .filter_map(|index| index.parse::<usize>().ok())
+                .map(Segment::Index),
+        );
+    }
+    segments
+}
+
+/// A key or list item of a block-style YAML document, `- key: value` giving two nodes.
+struct Node<'a> {
+    line: usize,
+    column: usize,
+    text: &'a str,
+    is_item: bool,
+}
+
+fn nodes(source: &str) -> Vec<Node<'_>> {
+    let mut nodes: Vec<Node> = Vec::new();
+    for (line, content) in source.lines().enumerate() {
+        let mut column: usize = content.len() - content.trim_start().len();
+        let mut text: &str = content.trim_start();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+        while let Some(rest) = text.strip_prefix('-') {
+            if !(rest.is_empty() || rest.starts_with(' ')) {
+                break;
+            }
+            nodes.push(Node {
+                line,
+                column,
+                text,
+                is_item: true,
+            });
+            let trimmed: &str = rest.trim_start();
+            column += text.len() - trimmed.len();
+            text = trimmed;
+        }
+        if !text.is_empty() {
+            nodes.push(Node {
+                line,
+                column,
+                text,
+                is_item: false,
+            });
+        }
+    }
+    nodes
+}
+
+/// Finds the 1-based line and column of the value at `path` in a block-style YAML document,
+/// falling back to the closest parent that can be found. The boolean tells whether the whole
+/// path was found.
+pub fn locate(source: &str, path: &str) -> (Option<(usize, usize)>, bool) {
+    let nodes: Vec<Node> = nodes(source);
+    let mut parent: Option<usize> = None;
+    let mut location: Option<(usize, usize)> = None;
+
+    for segment in parse_path(path) {
+        let start: usize = parent.map_or(0, |parent| parent + 1);
+        let parent_column: Option<usize> = parent.map(|parent| nodes[parent].column);
+        let children: Vec<usize> = (start..nodes.len())
+            .take_while(|&i| parent_column.is_none_or(|column| nodes[i].column > column))
+            .collect();
+        let Some(child_column) = children.first().map(|&i| nodes[i].column) else {
+            return (location, false);
+        };
+        let mut siblings = children
+            .into_iter()
+            .filter(|&i| nodes[i].column == child_column);
+
+        let found: Option<usize> = match segment {
+            Segment::Key(key) => siblings.find(|&i| {
+                !nodes[i].is_item
+                    && nodes[i]
+                        .text
+                        .trim_start_matches(['"', '\''])
+                        .strip_prefix(key)
+                        .is_some_and(|rest| rest.trim_start_matches(['"', '\'']).starts_with(':'))
+            }),
+            Segment::Index(index) => siblings.filter(|&i| nodes[i].is_item).nth(index),
+        };
+        let Some(found) = found else {
+            return (location, false);
+        };
+
+        let node: &Node = &nodes[found];
+        location = Some(match segment {
+            Segment::Key(key) => {
+                let key_end: usize = node.text.find(key).unwrap_or_default() + key.len();
+                let value: &str = node.text[key_end..]
+                    .trim_start_matches(['"', '\'', ':'])
+                    .trim_start();
+                if value.is_empty() || value.starts_with('#') {
+                    (node.line + 1, node.column + 1)
+                } else {
+                    (
+                        node.line + 1,
+                        node.column + node.text.len() - value.len() + 1,
+                    )
+                }
+            }
+            Segment::Index(_) => {
+                let value: &str = node.text[1..].trim_start();
+                (
+                    node.line + 1,
+                    node.column + node.text.len() - value.len() + 1,
+                )
+            }
+        });
+        parent = Some(found);
+    }
+
+    (location, true)
+}
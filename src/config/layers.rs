@@ -0,0 +1,374 @@
+use crate::config::check::{locate, parse_file, validate, with_suggestion, Diagnostic};
+use crate::config::Config;
+use crate::entries::Registry;
+use serde_yaml::{Mapping, Value};
+use std::path::{Path, PathBuf};
+
+/// The environment variable selecting a profile when `--profile` is not given.
+pub const PROFILE_ENV: &str = "AFETCH_PROFILE";
+const ENV_PREFIX: &str = "AFETCH_";
+/// The keys that can be overridden with `AFETCH_*` environment variables.
+const ENV_KEYS: [&str; 9] = [
+    "language",
+    "logo",
+    "text_color",
+    "text_color_header",
+    "disabled_entries",
+    "entries",
+    "formats",
+    "cache",
+    "timeout",
+];
+/// The keys whose environment variable holds a comma-separated list.
+const ENV_LIST_KEYS: [&str; 4] = [
+    "text_color",
+    "text_color_header",
+    "disabled_entries",
+    "entries",
+];
+
+/// The configuration file shared by every user of the machine.
+pub fn system_config_path() -> Option<PathBuf> {
+    #[cfg(unix)]
+    {
+        Some(PathBuf::from("/etc/afetch/config.yaml"))
+    }
+
+    #[cfg(windows)]
+    {
+        std::env::var_os("PROGRAMDATA").map(|program_data| {
+            PathBuf::from(program_data)
+                .join("afetch")
+                .join("config.yaml")
+        })
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        None
+    }
+}
+
+pub fn user_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|config_dir| config_dir.join("afetch").join("config.yaml"))
+}
+
+pub struct ConfigFile {
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// The configuration once every layer has been applied, along with the files it comes from.
+pub struct LoadedConfig {
+    pub config: Config,
+    pub files: Vec<PathBuf>,
+}
+
+/// Recursively merges `layer` into `base`: mappings are merged key by key, any other value
+/// replaces the previous one.
+pub fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Mapping(base), Value::Mapping(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+/// Reads a value the way YAML would, so that numbers and booleans keep their type.
+fn env_scalar(value: &str) -> Value {
+    if let Ok(number) = value.parse::<u64>() {
+        return Value::Number(number.into());
+    }
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(value.to_owned()),
+    }
+}
+
+/// Builds a layer from the `AFETCH_*` variables, `__` separating nested keys: `AFETCH_LANGUAGE=fr`,
+/// `AFETCH_LOGO__STATUS=disable` or `AFETCH_DISABLED_ENTRIES=battery,network`.
+pub fn env_layer<I: IntoIterator<Item = (String, String)>>(vars: I) -> Value {
+    let mut layer: Value = Value::Mapping(Mapping::new());
+
+    for (name, value) in vars {
+        let Some(name) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        let keys: Vec<String> = name.to_lowercase().split("__").map(str::to_owned).collect();
+        if !ENV_KEYS.contains(&keys[0].as_str()) {
+            continue;
+        }
+
+        let mut value: Value = if keys.len() == 1 && ENV_LIST_KEYS.contains(&keys[0].as_str()) {
+            Value::Sequence(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(env_scalar)
+                    .collect(),
+            )
+        } else {
+            env_scalar(&value)
+        };
+        for key in keys.into_iter().rev() {
+            let mut mapping: Mapping = Mapping::new();
+            mapping.insert(Value::String(key), value);
+            value = Value::Mapping(mapping);
+        }
+        merge(&mut layer, value);
+    }
+
+    layer
+}
+
+fn read_file(path: &Path, required: bool) -> Result<Option<ConfigFile>, Diagnostic> {
+    if !required && !path.exists() {
+        return Ok(None);
+    }
+    match std::fs::read_to_string(path) {
+        Ok(source) => Ok(Some(ConfigFile {
+            path: path.to_path_buf(),
+            source,
+        })),
+        Err(error) => Err(Diagnostic {
+            path: String::default(),
+            message: format!("the file cannot be read ({})", error),
+            file: Some(path.to_path_buf()),
+            location: None,
+        }),
+    }
+}
+
+/// Points a diagnostic at the file with the highest priority that sets its key, the active
+/// profile taking precedence over the top-level keys. The path of a key set by the profile is
+/// prefixed with it, e.g. `profiles.work.logo.status`.
+fn attribute(diagnostic: &mut Diagnostic, files: &[ConfigFile], profile: Option<&str>) {
+    let mut paths: Vec<String> = vec![diagnostic.path.clone()];
+    if let Some(profile) = profile {
+        paths.push(format!("profiles.{}.{}", profile, diagnostic.path));
+    }
+
+    let mut found: Option<(&String, &ConfigFile)> = None;
+    let mut found_location: Option<(usize, usize)> = None;
+    let mut partially_found: Option<(&ConfigFile, (usize, usize))> = None;
+    for path in &paths {
+        // A list replaces the one of the previous layers, so the file setting it sets its items,
+        // even when they cannot be located, e.g. in a `[a, b]` list.
+        let list_path: Option<&str> = path.find('[').map(|index| &path[..index]);
+        for file in files {
+            match locate(&file.source, path) {
+                (location, true) => (found, found_location) = (Some((path, file)), location),
+                (location, false)
+                    if list_path.is_some_and(|list_path| locate(&file.source, list_path).1) =>
+                {
+                    (found, found_location) = (Some((path, file)), location);
+                }
+                (Some(location), false) => partially_found = Some((file, location)),
+                (None, false) => {}
+            }
+        }
+    }
+
+    if let Some((path, file)) = found {
+        diagnostic.path = path.clone();
+        diagnostic.file = Some(file.path.clone());
+        diagnostic.location = found_location;
+    } else if let Some((file, location)) = partially_found {
+        diagnostic.file = Some(file.path.clone());
+        diagnostic.location = Some(location);
+    }
+}
+
+/// Sorts diagnostics by file, in the order they are loaded, then by location.
+fn sort(diagnostics: &mut [Diagnostic], files: &[ConfigFile]) {
+    diagnostics.sort_by_key(|diagnostic| {
+        (
+            files
+                .iter()
+                .position(|file| Some(&file.path) == diagnostic.file.as_ref()),
+            diagnostic.location,
+        )
+    });
+}
+
+/// The configuration files, by increasing priority: the system file then the user file, or
+/// `config_path` when given. Their keys are merged, those with an invalid value being left
+/// out and reported.
+fn read_files(
+    config_path: Option<&Path>,
+) -> Result<(Vec<ConfigFile>, Value, Vec<Diagnostic>), Vec<Diagnostic>> {
+    let mut files: Vec<ConfigFile> = Vec::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let candidates = system_config_path()
+        .map(|path| (path, false))
+        .into_iter()
+        .chain(match config_path {
+            Some(config_path) => Some((config_path.to_path_buf(), true)),
+            None => user_config_path().map(|path| (path, false)),
+        });
+    for (path, required) in candidates {
+        match read_file(&path, required) {
+            Ok(file) => files.extend(file),
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let mut merged: Value = Value::Mapping(Mapping::new());
+    for file in &files {
+        let (value, file_diagnostics): (Value, Vec<Diagnostic>) =
+            parse_file(&file.path, &file.source);
+        merge(&mut merged, value);
+        diagnostics.extend(file_diagnostics);
+    }
+    Ok((files, merged, diagnostics))
+}
+
+/// The names of the profiles defined in the merged files.
+fn profile_names(merged: &Value) -> Vec<String> {
+    merged
+        .get("profiles")
+        .and_then(Value::as_mapping)
+        .map(|profiles| {
+            profiles
+                .keys()
+                .filter_map(Value::as_str)
+                .map(str::to_owned)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Applies `profile` and the `AFETCH_*` environment variables to the merged files, then checks
+/// the resulting configuration.
+fn resolve(
+    files: &[ConfigFile],
+    mut merged: Value,
+    profile: Option<&str>,
+) -> Result<Config, Vec<Diagnostic>> {
+    let profiles: Option<Value> = merged
+        .as_mapping_mut()
+        .and_then(|mapping| mapping.remove("profiles"));
+    if let Some(profile) = profile {
+        match profiles.as_ref().and_then(|profiles| profiles.get(profile)) {
+            Some(profile_layer) => merge(&mut merged, profile_layer.clone()),
+            None => {
+                let names: Vec<&str> = profiles
+                    .as_ref()
+                    .and_then(Value::as_mapping)
+                    .map(|profiles| profiles.keys().filter_map(Value::as_str).collect())
+                    .unwrap_or_default();
+                return Err(vec![Diagnostic::new(
+                    "--profile",
+                    with_suggestion(
+                        format!("there is no profile named `{}`", profile),
+                        profile,
+                        names,
+                    ),
+                )]);
+            }
+        }
+    }
+
+    merge(
+        &mut merged,
+        env_layer(std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        })),
+    );
+
+    let config: Config = serde_yaml::from_value(merged).map_err(|error| {
+        vec![Diagnostic::new(
+            "",
+            format!(
+                "{} (set by a profile or an `AFETCH_*` environment variable)",
+                error
+            ),
+        )]
+    })?;
+    let mut diagnostics: Vec<Diagnostic> = validate(&config, &Registry::from_config(&config));
+    if diagnostics.is_empty() {
+        return Ok(config);
+    }
+    for diagnostic in &mut diagnostics {
+        attribute(diagnostic, files, profile);
+    }
+    Err(diagnostics)
+}
+
+/// Loads the configuration from, by increasing priority: the system file, the user file (or
+/// `config_path` when given), the selected profile and the `AFETCH_*` environment variables.
+pub fn load(
+    config_path: Option<&Path>,
+    profile: Option<&str>,
+) -> Result<LoadedConfig, Vec<Diagnostic>> {
+    let (files, merged, mut diagnostics) = read_files(config_path)?;
+    match resolve(&files, merged, profile) {
+        Ok(config) if diagnostics.is_empty() => Ok(LoadedConfig {
+            config,
+            files: files.into_iter().map(|file| file.path).collect(),
+        }),
+        result => {
+            diagnostics.extend(result.err().unwrap_or_default());
+            sort(&mut diagnostics, &files);
+            Err(diagnostics)
+        }
+    }
+}
+
+/// Loads the configuration like [`load`], checking it with every profile it defines applied in
+/// turn as well as without any.
+pub fn check(
+    config_path: Option<&Path>,
+    profile: Option<&str>,
+) -> Result<LoadedConfig, Vec<Diagnostic>> {
+    let (files, merged, mut diagnostics) = read_files(config_path)?;
+    let mut profiles: Vec<Option<String>> = vec![None];
+    profiles.extend(profile_names(&merged).into_iter().map(Some));
+    if let Some(profile) =
+        profile.filter(|profile| !profiles.contains(&Some((*profile).to_owned())))
+    {
+        profiles.push(Some(profile.to_owned()));
+    }
+
+    let mut loaded: Option<Config> = None;
+    for checked in &profiles {
+        match resolve(&files, merged.clone(), checked.as_deref()) {
+            Ok(config) => {
+                if checked.as_deref() == profile {
+                    loaded = Some(config);
+                }
+            }
+            Err(profile_diagnostics) => {
+                for diagnostic in profile_diagnostics {
+                    if !diagnostics.contains(&diagnostic) {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+        }
+    }
+
+    match loaded {
+        Some(config) if diagnostics.is_empty() => Ok(LoadedConfig {
+            config,
+            files: files.into_iter().map(|file| file.path).collect(),
+        }),
+        _ => {
+            sort(&mut diagnostics, &files);
+            Err(diagnostics)
+        }
+    }
+}
@@ -0,0 +1,626 @@
+use crate::config::check::with_suggestion;
+use crate::entries::Registry;
+use crate::logos::LogoColor;
+use crate::translations::{language_code_list, FALLBACK_CODE};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+pub mod check;
+pub mod layers;
+
+/// Parses one of the `keywords` case-insensitively, the first spelling of each value being the
+/// one it is written back with.
+fn parse_keyword<T: Copy>(value: &str, keywords: &[(&'static str, T)]) -> Result<T, String> {
+    let lowercase: String = value.trim().to_lowercase();
+    keywords
+        .iter()
+        .find(|(keyword, _)| *keyword == lowercase)
+        .map(|(_, parsed)| *parsed)
+        .ok_or_else(|| {
+            let mut expected: Vec<&str> = keywords.iter().map(|(keyword, _)| *keyword).collect();
+            expected.dedup();
+            with_suggestion(
+                format!(
+                    "expected one of `{}`, found `{}`",
+                    expected.join("`, `"),
+                    value
+                ),
+                &lowercase,
+                keywords.iter().map(|(keyword, _)| *keyword),
+            )
+        })
+}
+
+/// Implements `FromStr`, `Display` and the string conversions serde goes through for an enum
+/// spelled with keywords in the config.
+macro_rules! keyword_enum {
+    ($name:ident, $keywords:expr) => {
+        impl FromStr for $name {
+            type Err = String;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                parse_keyword(value, $keywords)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = String;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let keyword: &str = $keywords
+                    .iter()
+                    .find(|(_, value)| value == self)
+                    .map(|(keyword, _)| *keyword)
+                    .unwrap_or_default();
+                write!(f, "{}", keyword)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.to_string()
+            }
+        }
+    };
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum LogoStatus {
+    #[default]
+    Enable,
+    Disable,
+}
+
+keyword_enum!(
+    LogoStatus,
+    &[
+        ("enable", LogoStatus::Enable),
+        ("enabled", LogoStatus::Enable),
+        ("disable", LogoStatus::Disable),
+        ("disabled", LogoStatus::Disable),
+    ]
+);
+
+/// How the logo is drawn, set with the `char_type` key.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum LogoRenderer {
+    /// The builtin logo of the distribution, drawn with Braille characters.
+    #[default]
+    Braille,
+    /// The image at `picture_path`, only available with the `image` feature.
+    Picture,
+    /// The image at `picture_path` converted to Braille characters, `picture_width` columns
+    /// wide, only available with the `image` feature.
+    PictureBraille,
+    /// The image at `picture_path` converted to `▀` half blocks, `picture_width` columns wide,
+    /// only available with the `image` feature.
+    PictureBlocks,
+}
+
+impl LogoRenderer {
+    /// Whether the logo is drawn from the image at `picture_path`.
+    pub fn is_picture(self) -> bool {
+        self != Self::Braille
+    }
+}
+
+keyword_enum!(
+    LogoRenderer,
+    &[
+        ("braille", LogoRenderer::Braille),
+        ("picture", LogoRenderer::Picture),
+        ("image", LogoRenderer::Picture),
+        ("picture-braille", LogoRenderer::PictureBraille),
+        ("picture-blocks", LogoRenderer::PictureBlocks),
+    ]
+);
+
+/// Which variant of the logo is printed, set with the `size` key.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum LogoSize {
+    /// The large variant when it fits in the terminal next to the information, else the small
+    /// one, else no logo at all.
+    #[default]
+    Auto,
+    Small,
+    Large,
+}
+
+keyword_enum!(
+    LogoSize,
+    &[
+        ("auto", LogoSize::Auto),
+        ("small", LogoSize::Small),
+        ("large", LogoSize::Large),
+    ]
+);
+
+/// Where the logo is printed relative to the information, set with the `position` key.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum LogoPosition {
+    #[default]
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+keyword_enum!(
+    LogoPosition,
+    &[
+        ("left", LogoPosition::Left),
+        ("right", LogoPosition::Right),
+        ("top", LogoPosition::Top),
+        ("bottom", LogoPosition::Bottom),
+        ("below", LogoPosition::Bottom),
+    ]
+);
+
+/// How the image of `picture` logos is drawn, set with the `picture_protocol` key.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum PictureProtocol {
+    /// The graphics protocol the terminal is known to support, else half blocks.
+    #[default]
+    Auto,
+    Kitty,
+    Iterm,
+    Sixel,
+    /// `▀` half blocks, which every terminal with 24-bit colors can print.
+    Blocks,
+}
+
+keyword_enum!(
+    PictureProtocol,
+    &[
+        ("auto", PictureProtocol::Auto),
+        ("kitty", PictureProtocol::Kitty),
+        ("iterm", PictureProtocol::Iterm),
+        ("sixel", PictureProtocol::Sixel),
+        ("blocks", PictureProtocol::Blocks),
+    ]
+);
+
+/// The language of the labels, `auto` or the code of a bundled or user translation.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Default)]
+#[serde(try_from = "String", into = "String")]
+pub enum Language {
+    /// Follows the locale of the system, falling back to English.
+    #[default]
+    Auto,
+    Code(String),
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value: &str = value.trim();
+        if value.eq_ignore_ascii_case("auto") {
+            return Ok(Self::Auto);
+        }
+        let codes: Vec<String> = language_code_list();
+        codes
+            .iter()
+            .find(|code| code.eq_ignore_ascii_case(value))
+            .map(|code| Self::Code(code.clone()))
+            .ok_or_else(|| {
+                with_suggestion(
+                    format!(
+                        "expected `auto` or one of `{}`, found `{}`",
+                        codes.join("`, `"),
+                        value
+                    ),
+                    value,
+                    codes.iter().map(String::as_str),
+                )
+            })
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Code(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+impl From<Language> for String {
+    fn from(value: Language) -> Self {
+        value.to_string()
+    }
+}
+
+impl Language {
+    /// The code of the translation to use, see [`crate::translations::Translation`].
+    ///
+    /// The whole locale is looked for first, so that a `pt-BR` translation is preferred to a
+    /// `pt` one.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Auto => {
+                let locale: String = sys_locale::get_locale()
+                    .unwrap_or_else(|| String::from("en-US"))
+                    .replace('_', "-");
+                let language: &str = locale.split('-').next().unwrap_or(&locale);
+                let codes: Vec<String> = language_code_list();
+                let code: Option<String> =
+                    [locale.as_str(), language].into_iter().find_map(|wanted| {
+                        codes
+                            .iter()
+                            .find(|code| code.eq_ignore_ascii_case(wanted))
+                            .cloned()
+                    });
+                code.unwrap_or_else(|| FALLBACK_CODE.to_owned())
+            }
+            Self::Code(code) => code.clone(),
+        }
+    }
+}
+
+/// The name of an entry, as used in `disabled_entries`, `entries` and `--only`.
+///
+/// Names that are not builtin entries are kept as [`EntryId::Custom`], they are checked
+/// against the custom entries by [`check::validate`].
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum EntryId {
+    Os,
+    Host,
+    Kernel,
+    Uptime,
+    Packages,
+    Shell,
+    Resolution,
+    Desktop,
+    /// Only hides the version of the desktop entry.
+    DesktopVersion,
+    Wm,
+    Terminal,
+    TerminalFont,
+    Cpu,
+    /// Only hides the usage of the CPU entry.
+    CpuUsage,
+    Gpu,
+    Memory,
+    Network,
+    Disk,
+    Disks,
+    PublicIp,
+    Battery,
+    ColorBlocks,
+    Custom(String),
+}
+
+const BUILTIN_ENTRY_IDS: [(&str, EntryId); 22] = [
+    ("os", EntryId::Os),
+    ("host", EntryId::Host),
+    ("kernel", EntryId::Kernel),
+    ("uptime", EntryId::Uptime),
+    ("packages", EntryId::Packages),
+    ("shell", EntryId::Shell),
+    ("resolution", EntryId::Resolution),
+    ("desktop", EntryId::Desktop),
+    ("desktop-version", EntryId::DesktopVersion),
+    ("wm", EntryId::Wm),
+    ("terminal", EntryId::Terminal),
+    ("terminal-font", EntryId::TerminalFont),
+    ("cpu", EntryId::Cpu),
+    ("cpu-usage", EntryId::CpuUsage),
+    ("gpu", EntryId::Gpu),
+    ("memory", EntryId::Memory),
+    ("network", EntryId::Network),
+    ("disk", EntryId::Disk),
+    ("disks", EntryId::Disks),
+    ("public-ip", EntryId::PublicIp),
+    ("battery", EntryId::Battery),
+    ("color-blocks", EntryId::ColorBlocks),
+];
+
+impl EntryId {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Custom(id) => id,
+            id => BUILTIN_ENTRY_IDS
+                .iter()
+                .find(|(_, builtin)| builtin == id)
+                .map(|(name, _)| *name)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&str> for EntryId {
+    fn from(id: &str) -> Self {
+        BUILTIN_ENTRY_IDS
+            .iter()
+            .find(|(name, _)| *name == id)
+            .map_or_else(
+                || Self::Custom(id.to_owned()),
+                |(_, builtin)| builtin.clone(),
+            )
+    }
+}
+
+impl From<String> for EntryId {
+    fn from(id: String) -> Self {
+        Self::from(id.as_str())
+    }
+}
+
+impl From<EntryId> for String {
+    fn from(id: EntryId) -> Self {
+        id.as_str().to_owned()
+    }
+}
+
+impl fmt::Display for EntryId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct LogoConfig {
+    #[serde(default)]
+    pub status: LogoStatus,
+    #[serde(default)]
+    pub char_type: LogoRenderer,
+    #[serde(default)]
+    pub size: LogoSize,
+    #[serde(default)]
+    pub position: LogoPosition,
+    /// The number of spaces before the first column and between the logo and the information.
+    #[serde(default = "default_logo_padding")]
+    pub padding: usize,
+    #[serde(default = "default_picture_path")]
+    pub picture_path: String,
+    /// The number of columns of the image.
+    #[serde(default = "default_picture_width")]
+    pub picture_width: usize,
+    /// The largest number of rows of the image, which is made narrower to fit in them.
+    #[serde(default)]
+    pub picture_height: Option<usize>,
+    #[serde(default)]
+    pub picture_protocol: PictureProtocol,
+    /// The colors replacing those of the `${c1}`..`${c6}` placeholders of the logo.
+    #[serde(default)]
+    pub colors: BTreeMap<String, LogoColor>,
+}
+
+impl Default for LogoConfig {
+    fn default() -> Self {
+        Self {
+            status: LogoStatus::default(),
+            char_type: LogoRenderer::default(),
+            size: LogoSize::default(),
+            position: LogoPosition::default(),
+            padding: default_logo_padding(),
+            picture_path: default_picture_path(),
+            picture_width: default_picture_width(),
+            picture_height: None,
+            picture_protocol: PictureProtocol::default(),
+            colors: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_logo_padding() -> usize {
+    3
+}
+
+fn default_picture_path() -> String {
+    "none".to_owned()
+}
+
+fn default_picture_width() -> usize {
+    40
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub logo: LogoConfig,
+    #[serde(default = "default_text_color")]
+    pub text_color: Vec<u8>,
+    #[serde(default = "default_text_color_header")]
+    pub text_color_header: Option<Vec<u8>>,
+    #[serde(default = "default_disabled_entries")]
+    pub disabled_entries: Vec<EntryId>,
+    #[serde(default)]
+    pub entries: Option<Vec<LayoutItem>>,
+    #[serde(default)]
+    pub custom: Vec<CustomEntryConfig>,
+    #[serde(default)]
+    pub formats: HashMap<String, String>,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub timeout: TimeoutConfig,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CacheConfig {
+    #[serde(default = "default_cache_enabled")]
+    pub enabled: bool,
+    /// Overrides the time in seconds the value of an entry is cached for, `0` disabling it.
+    #[serde(default)]
+    pub ttl: HashMap<String, u64>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_cache_enabled(),
+            ttl: HashMap::new(),
+        }
+    }
+}
+
+fn default_cache_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TimeoutConfig {
+    /// The time in milliseconds after which the entries still being gathered are given up and
+    /// the external commands still running are killed.
+    #[serde(default = "default_global_timeout")]
+    pub global_ms: u64,
+    /// Shorter deadlines in milliseconds for some entries.
+    #[serde(default)]
+    pub entries: HashMap<String, u64>,
+    /// Whether the entries that missed their deadline are printed as timed out, or hidden.
+    #[serde(default)]
+    pub show_timed_out: bool,
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            global_ms: default_global_timeout(),
+            entries: HashMap::new(),
+            show_timed_out: false,
+        }
+    }
+}
+
+fn default_global_timeout() -> u64 {
+    3000
+}
+
+/// An entry whose value is the output of a shell command.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct CustomEntryConfig {
+    #[serde(default)]
+    pub id: Option<String>,
+    pub label: String,
+    pub command: String,
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl CustomEntryConfig {
+    /// The id used to reference this entry in `entries` and `disabled_entries`, derived from
+    /// the label when not set.
+    pub fn id(&self) -> String {
+        self.id
+            .clone()
+            .unwrap_or_else(|| self.label.trim().to_lowercase().replace(' ', "-"))
+    }
+}
+
+impl Config {
+    /// Whether an entry should be collected: when an `entries` layout is set only the entries
+    /// it lists are, otherwise every entry that is not part of `disabled_entries`.
+    pub fn is_entry_enabled(&self, id: &str) -> bool {
+        let id: EntryId = EntryId::from(id);
+        match &self.entries {
+            Some(entries) => entries.contains(&LayoutItem::Entry(id)),
+            None => !self.disabled_entries.contains(&id),
+        }
+    }
+
+    /// Whether an entry has been listed in `disabled_entries`, whatever the layout.
+    pub fn is_disabled(&self, id: &EntryId) -> bool {
+        self.disabled_entries.contains(id)
+    }
+}
+
+/// A line of the `entries` layout.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(from = "LayoutItemRepr", into = "LayoutItemRepr")]
+pub enum LayoutItem {
+    /// The `user@host` line.
+    Title,
+    /// The line drawn under the title.
+    Separator,
+    /// An empty line.
+    Spacer,
+    ColorBlocks,
+    Section(String),
+    Entry(EntryId),
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+enum LayoutItemRepr {
+    Section { section: String },
+    Name(String),
+}
+
+impl From<LayoutItemRepr> for LayoutItem {
+    fn from(repr: LayoutItemRepr) -> Self {
+        match repr {
+            LayoutItemRepr::Section { section } => Self::Section(section),
+            LayoutItemRepr::Name(name) => match name.as_str() {
+                "title" => Self::Title,
+                "separator" => Self::Separator,
+                "spacer" => Self::Spacer,
+                "color-blocks" => Self::ColorBlocks,
+                _ => Self::Entry(EntryId::from(name)),
+            },
+        }
+    }
+}
+
+impl From<LayoutItem> for LayoutItemRepr {
+    fn from(item: LayoutItem) -> Self {
+        match item {
+            LayoutItem::Title => Self::Name("title".to_owned()),
+            LayoutItem::Separator => Self::Name("separator".to_owned()),
+            LayoutItem::Spacer => Self::Name("spacer".to_owned()),
+            LayoutItem::ColorBlocks => Self::Name("color-blocks".to_owned()),
+            LayoutItem::Section(section) => Self::Section { section },
+            LayoutItem::Entry(id) => Self::Name(id.into()),
+        }
+    }
+}
+
+fn default_text_color() -> Vec<u8> {
+    vec![255, 255, 255]
+}
+
+fn default_text_color_header() -> Option<Vec<u8>> {
+    None
+}
+
+fn default_disabled_entries() -> Vec<EntryId> {
+    let mut disabled_entries: Vec<EntryId> = Registry::builtin()
+        .entries()
+        .iter()
+        .filter(|entry| !entry.default_enabled)
+        .map(|entry| EntryId::from(entry.id.as_str()))
+        .collect();
+    disabled_entries.push(EntryId::CpuUsage);
+    disabled_entries
+}
@@ -0,0 +1,167 @@
+use crate::config::EntryId;
+use crate::entries::Entry;
+use crate::system::report::{DesktopInfo, DiskUsageInfo, EntryValue};
+
+const DAY: u64 = 24 * 60 * 60;
+/// The package databases that change when packages are installed or removed.
+const PACKAGE_DATABASES: [&str; 14] = [
+    "/var/lib/pacman/local",
+    "/var/lib/dpkg/status",
+    "/var/lib/rpm",
+    "/var/cache/dnf/packages.db",
+    "/lib/apk/db/installed",
+    "/var/db/xbps",
+    "/var/db/pkg",
+    "/var/lib/flatpak/app",
+    "~/.local/share/flatpak/app",
+    "/var/lib/snapd/state.json",
+    "/nix/var/nix/profiles",
+    "/nix/var/nix/profiles/per-user",
+    "~/.local/state/nix/profiles",
+    "C:\\ProgramData\\chocolatey\\lib",
+];
+/// The graphics devices and the version of the NVIDIA driver, which change along with the GPUs.
+const GPU_DEVICES: [&str; 2] = ["/sys/class/drm", "/proc/driver/nvidia/version"];
+/// The binaries whose version is printed by the desktop entry.
+const DESKTOP_BINARIES: [&str; 6] = [
+    "/usr/bin/plasmashell",
+    "/usr/bin/gnome-shell",
+    "/usr/bin/xfce4-session",
+    "/usr/bin/mate-session",
+    "/usr/bin/cinnamon",
+    "/usr/bin/budgie-desktop",
+];
+const DESKTOP_ENV: [&str; 5] = [
+    "DESKTOP_SESSION",
+    "XDG_CURRENT_DESKTOP",
+    "GNOME_DESKTOP_SESSION_ID",
+    "MATE_DESKTOP_SESSION_ID",
+    "TDE_FULL_SESSION",
+];
+const TERMINAL_ENV: [&str; 4] = [
+    "TERM",
+    "TERM_PROGRAM",
+    "KONSOLE_VERSION",
+    "KONSOLE_DBUS_SESSION",
+];
+
+fn non_empty(value: String) -> Option<String> {
+    let value: String = value.trim().to_owned();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+pub fn entries() -> Vec<Entry> {
+    vec![
+        Entry::new("os", "label-os", true, |infos, _| async move {
+            non_empty(infos.get_os_name()).map(EntryValue::Text)
+        }),
+        Entry::new("host", "label-host", true, |infos, _| async move {
+            non_empty(infos.get_host()).map(EntryValue::Text)
+        }),
+        Entry::new("kernel", "label-kernel", true, |infos, _| async move {
+            non_empty(infos.get_kernel()).map(EntryValue::Text)
+        }),
+        Entry::new("uptime", "label-uptime", true, |infos, _| async move {
+            Some(EntryValue::Duration(infos.get_uptime()))
+        })
+        .with_placeholders(&["value", "days", "hours", "minutes", "seconds"]),
+        Entry::new("packages", "label-packages", true, |infos, _| async move {
+            Some(infos.get_packages().await)
+                .filter(|packages| !packages.is_empty())
+                .map(EntryValue::Packages)
+        })
+        .with_placeholders(&["value", "total"])
+        .cached(DAY, &PACKAGE_DATABASES, &[]),
+        Entry::new("shell", "label-shell", true, |infos, _| async move {
+            non_empty(infos.get_shell()).map(EntryValue::Text)
+        }),
+        Entry::new(
+            "resolution",
+            "label-resolution",
+            true,
+            |infos, _| async move { non_empty(infos.get_screens_resolution()).map(EntryValue::Text) },
+        ),
+        Entry::new(
+            "desktop",
+            "label-desktop",
+            true,
+            |infos, config| async move {
+                let (name, version): (String, String) = infos.get_de();
+                let version: Option<String> = if config.is_disabled(&EntryId::DesktopVersion) {
+                    None
+                } else {
+                    non_empty(version)
+                };
+                non_empty(name).map(|name| EntryValue::Desktop(DesktopInfo { name, version }))
+            },
+        )
+        .with_placeholders(&["value", "name", "version"])
+        .cached(DAY, &DESKTOP_BINARIES, &DESKTOP_ENV),
+        Entry::new("wm", "label-wm", false, |infos, _| async move {
+            non_empty(infos.get_wm()).map(EntryValue::Text)
+        }),
+        Entry::new("terminal", "label-terminal", true, |infos, _| async move {
+            non_empty(infos.get_terminal()).map(EntryValue::Text)
+        }),
+        Entry::new(
+            "terminal-font",
+            "label-terminal-font",
+            true,
+            |infos, _| async move { non_empty(infos.get_terminal_font()).map(EntryValue::Text) },
+        )
+        .cached(60 * 60, &[], &TERMINAL_ENV),
+        Entry::new("cpu", "label-cpu", true, |infos, config| async move {
+            infos.get_cpu().map(|mut cpu| {
+                if config.is_disabled(&EntryId::CpuUsage) {
+                    cpu.usage = None;
+                }
+                EntryValue::Cpu(cpu)
+            })
+        })
+        .with_placeholders(&["value", "brand", "usage", "freq", "cores", "threads"]),
+        Entry::new("gpu", "label-gpu", true, |infos, _| async move {
+            Some(infos.get_gpus())
+                .filter(|gpus| !gpus.is_empty())
+                .map(EntryValue::Gpus)
+        })
+        .cached(DAY, &GPU_DEVICES, &[]),
+        Entry::new("memory", "label-memory", true, |infos, _| async move {
+            Some(EntryValue::Memory(infos.get_memory()))
+        })
+        .with_placeholders(&["value", "used", "total", "percent"]),
+        Entry::new("network", "label-network", false, |infos, _| async move {
+            Some(EntryValue::Network(infos.get_network()))
+        })
+        .with_placeholders(&["value", "sent", "received"]),
+        Entry::new("disk", "label-disk", true, |infos, _| async move {
+            Some(infos.get_disks())
+                .filter(|disks| !disks.is_empty())
+                .map(EntryValue::Disks)
+        })
+        .with_placeholders(&["value", "mount_point", "used", "total", "percent"]),
+        Entry::new("disks", "label-disks", true, |infos, _| async move {
+            let (used, total): (u64, u64) = infos
+                .get_disks()
+                .iter()
+                .fold((0, 0), |(used, total), disk| {
+                    (used + disk.used, total + disk.total)
+                });
+            Some(EntryValue::DiskUsage(DiskUsageInfo { used, total }))
+        })
+        .with_placeholders(&["value", "used", "total", "percent"]),
+        Entry::new(
+            "public-ip",
+            "label-public-ip",
+            false,
+            |infos, _| async move { non_empty(infos.get_public_ip()).map(EntryValue::Text) },
+        ),
+        Entry::new("battery", "label-battery", false, |infos, _| async move {
+            infos.get_battery().map(EntryValue::Battery)
+        })
+        .with_placeholders(&["value", "percentage"]),
+    ]
+}
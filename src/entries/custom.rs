@@ -0,0 +1,63 @@
+use crate::config::CustomEntryConfig;
+use crate::entries::Entry;
+use crate::system::report::EntryValue;
+use crate::utils::{return_str_from_command, return_str_from_command_with_timeout};
+use std::process::Command;
+use std::time::Duration;
+
+fn shell_command(command: &str) -> Command {
+    #[cfg(target_os = "windows")]
+    {
+        let mut shell: Command = Command::new("cmd");
+        shell.arg("/C").arg(command);
+        shell
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut shell: Command = Command::new("sh");
+        shell.arg("-c").arg(command);
+        shell
+    }
+}
+
+fn run(command: &str, timeout_ms: Option<u64>) -> Option<String> {
+    let output: String = match timeout_ms {
+        Some(timeout_ms) => return_str_from_command_with_timeout(
+            &mut shell_command(command),
+            Duration::from_millis(timeout_ms),
+        ),
+        None => return_str_from_command(&mut shell_command(command)),
+    };
+
+    let output: String = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    if output.is_empty() {
+        None
+    } else {
+        Some(output)
+    }
+}
+
+pub fn entries(custom_entries: &[CustomEntryConfig]) -> Vec<Entry> {
+    custom_entries
+        .iter()
+        .map(|custom_entry| {
+            let command: String = custom_entry.command.clone();
+            let timeout_ms: Option<u64> = custom_entry.timeout_ms;
+            Entry::new(
+                &custom_entry.id(),
+                &format!("{}: ", custom_entry.label),
+                true,
+                move |_, _| {
+                    let command: String = command.clone();
+                    async move { run(&command, timeout_ms).map(EntryValue::Text) }
+                },
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,323 @@
+use crate::cache::{Cache, CachePolicy};
+use crate::config::{Config, EntryId, LayoutItem};
+use crate::system::environment::Environment;
+use crate::system::infos::Infos;
+use crate::system::report::{EntryValue, FetchReport, ReportEntry};
+use crate::template::Template;
+use crate::timings::{self, TimingKind};
+use crate::utils::{kill_overdue_commands, set_command_deadline, with_command_deadline};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::runtime::Handle;
+use whoami::{fallible::hostname, username};
+
+pub mod builtin;
+pub mod custom;
+
+pub type EntryFuture = Pin<Box<dyn Future<Output = Option<EntryValue>> + Send>>;
+pub type Collector = Box<dyn Fn(Arc<Infos>, Arc<Config>) -> EntryFuture + Send + Sync>;
+
+/// A line (or group of lines) that afetch can print, along with the way to gather its value.
+pub struct Entry {
+    pub id: String,
+    pub label_key: String,
+    pub default_enabled: bool,
+    /// The placeholders that can be used in the format template of this entry.
+    pub placeholders: Vec<&'static str>,
+    /// The default cache policy of this entry, `None` meaning that it is always collected.
+    pub cache: Option<CachePolicy>,
+    pub collector: Collector,
+}
+
+impl Entry {
+    pub fn new<F, Fut>(id: &str, label_key: &str, default_enabled: bool, collector: F) -> Self
+    where
+        F: Fn(Arc<Infos>, Arc<Config>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<EntryValue>> + Send + 'static,
+    {
+        Self {
+            id: id.to_owned(),
+            label_key: label_key.to_owned(),
+            default_enabled,
+            placeholders: vec!["value"],
+            cache: None,
+            collector: Box::new(move |infos, config| Box::pin(collector(infos, config))),
+        }
+    }
+
+    pub fn with_placeholders(mut self, placeholders: &[&'static str]) -> Self {
+        self.placeholders = placeholders.to_vec();
+        self
+    }
+
+    /// Caches the value of this entry for `ttl` seconds, or until one of the `watched` files or
+    /// `env` variables changes. The watched paths starting with `~/` are in the home folder, see
+    /// [`Registry::cache_policy`].
+    pub fn cached(mut self, ttl: u64, watched: &[&str], env: &[&str]) -> Self {
+        self.cache = Some(CachePolicy {
+            ttl: Duration::from_secs(ttl),
+            watched: watched.iter().map(PathBuf::from).collect(),
+            env: env.iter().map(|name| (*name).to_owned()).collect(),
+        });
+        self
+    }
+}
+
+/// The ordered list of entries afetch collects and prints.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry holding every entry shipped with afetch, in the default print order.
+    pub fn builtin() -> Self {
+        let mut registry: Self = Self::new();
+        for entry in builtin::entries() {
+            registry.register(entry);
+        }
+        registry
+    }
+
+    /// The builtin entries followed by the custom command entries defined in the config.
+    pub fn from_config(config: &Config) -> Self {
+        let mut registry: Self = Self::builtin();
+        for entry in custom::entries(&config.custom) {
+            registry.register(entry);
+        }
+        registry
+    }
+
+    /// Adds an entry at the end of the registry, replacing any entry with the same id.
+    pub fn register(&mut self, entry: Entry) {
+        if let Some(index) = self
+            .entries
+            .iter()
+            .position(|registered| registered.id == entry.id)
+        {
+            self.entries[index] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn is_enabled(&self, id: &str, config: &Config) -> bool {
+        self.get(id).is_some() && config.is_entry_enabled(id)
+    }
+
+    /// The lines to print, either taken from the `entries` config key or built from the
+    /// registry order.
+    pub fn layout(&self, config: &Config) -> Vec<LayoutItem> {
+        if let Some(entries) = &config.entries {
+            return entries.clone();
+        }
+
+        let mut layout: Vec<LayoutItem> = vec![LayoutItem::Title, LayoutItem::Separator];
+        layout.extend(
+            self.entries
+                .iter()
+                .map(|entry| LayoutItem::Entry(EntryId::from(entry.id.as_str()))),
+        );
+        if config.is_entry_enabled("color-blocks") {
+            layout.extend([
+                LayoutItem::Spacer,
+                LayoutItem::Spacer,
+                LayoutItem::ColorBlocks,
+            ]);
+        }
+        layout
+    }
+
+    /// Parses the `formats` templates of the config, checking that each one belongs to a
+    /// known entry and only uses the placeholders of that entry.
+    pub fn parse_formats(&self, config: &Config) -> Result<HashMap<String, Template>, Vec<String>> {
+        let mut templates: HashMap<String, Template> = HashMap::new();
+        let mut errors: Vec<String> = Vec::new();
+
+        for (id, format) in &config.formats {
+            let entry: &Entry = match self.get(id) {
+                Some(entry) => entry,
+                None => {
+                    errors.push(format!(
+                        "`formats.{}`: there is no entry named `{}`",
+                        id, id
+                    ));
+                    continue;
+                }
+            };
+            match Template::parse_with_placeholders(format, &entry.placeholders) {
+                Ok(template) => {
+                    templates.insert(id.clone(), template);
+                }
+                Err(error) => errors.push(format!("`formats.{}`: {}", id, error)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(templates)
+        } else {
+            errors.sort();
+            Err(errors)
+        }
+    }
+
+    /// The cache policy of an entry, the `cache.ttl` config key taking precedence over the
+    /// default one of the entry. The watched paths are resolved in `environment`.
+    pub fn cache_policy(
+        &self,
+        entry: &Entry,
+        config: &Config,
+        environment: &Environment,
+    ) -> Option<CachePolicy> {
+        if !config.cache.enabled {
+            return None;
+        }
+        let mut policy: CachePolicy = match config.cache.ttl.get(&entry.id) {
+            Some(0) => return None,
+            Some(ttl) => {
+                let mut policy: CachePolicy = entry.cache.clone().unwrap_or(CachePolicy {
+                    ttl: Duration::default(),
+                    watched: Vec::new(),
+                    env: Vec::new(),
+                });
+                policy.ttl = Duration::from_secs(*ttl);
+                policy
+            }
+            None => entry.cache.clone()?,
+        };
+        policy.watched = policy
+            .watched
+            .iter()
+            .map(|path| environment.expand_path(path))
+            .collect();
+        Some(policy)
+    }
+
+    /// The instant after which an entry whose collection started at `started` is given up: its
+    /// `timeout.entries` deadline, if any, without exceeding the global one.
+    pub fn deadline(&self, entry: &Entry, config: &Config, started: Instant) -> Instant {
+        let global_timeout: u64 = config.timeout.global_ms;
+        let timeout: u64 = config
+            .timeout
+            .entries
+            .get(&entry.id)
+            .map_or(global_timeout, |timeout| (*timeout).min(global_timeout));
+        started + Duration::from_millis(timeout)
+    }
+
+    /// Runs the collectors of all enabled entries in parallel on blocking threads and gathers
+    /// their values, taking them from the cache when it holds a valid one. Entries that miss
+    /// their deadline are dropped, or reported as timed out if `timeout.show_timed_out` is set.
+    pub async fn collect(
+        &self,
+        infos: Arc<Infos>,
+        config: Arc<Config>,
+        cache: &Cache,
+    ) -> FetchReport {
+        let started: Instant = Instant::now();
+        set_command_deadline(started + Duration::from_millis(config.timeout.global_ms));
+        let handles = self
+            .entries
+            .iter()
+            .filter(|entry| self.is_enabled(&entry.id, &config))
+            .map(|entry| {
+                let policy: Option<CachePolicy> =
+                    self.cache_policy(entry, &config, &infos.environment);
+                let cached: Option<EntryValue> = policy
+                    .as_ref()
+                    .and_then(|policy| cache.get(&entry.id, policy));
+                let deadline: Instant = self.deadline(entry, &config, started);
+                let handle = if cached.is_none() {
+                    let collector: EntryFuture =
+                        (entry.collector)(Arc::clone(&infos), Arc::clone(&config));
+                    let runtime: Handle = Handle::current();
+                    Some(tokio::task::spawn_blocking(move || {
+                        let collector_started: Instant = Instant::now();
+                        // The commands of the entry are killed at its own deadline.
+                        let value: Option<EntryValue> =
+                            with_command_deadline(Some(deadline), || runtime.block_on(collector));
+                        (value, collector_started.elapsed())
+                    }))
+                } else {
+                    None
+                };
+                (entry, policy, cached, deadline, handle)
+            })
+            .collect::<Vec<_>>();
+
+        let mut report: FetchReport = FetchReport {
+            username: username(),
+            hostname: hostname().unwrap_or_default(),
+            entries: Vec::with_capacity(handles.len()),
+        };
+        for (entry, policy, cached, deadline, handle) in handles {
+            let value: Option<EntryValue> = match (cached, handle) {
+                (Some(value), _) => {
+                    timings::record(
+                        TimingKind::Entry,
+                        &entry.id,
+                        Duration::default(),
+                        Some("cached"),
+                    );
+                    Some(value)
+                }
+                (None, Some(mut handle)) => {
+                    match tokio::time::timeout_at(deadline.into(), &mut handle).await {
+                        Ok(Ok((value, duration))) => {
+                            timings::record(TimingKind::Entry, &entry.id, duration, None);
+                            if let (Some(policy), Some(value)) = (&policy, &value) {
+                                cache.insert(&entry.id, policy, value);
+                            }
+                            value
+                        }
+                        Ok(Err(error)) => {
+                            println!("Error while fetching the {} entry: {}", entry.id, error);
+                            None
+                        }
+                        Err(_) => {
+                            // A running collector cannot be stopped, the runtime is shut down
+                            // without waiting for it, but the commands it runs are killed.
+                            handle.abort();
+                            kill_overdue_commands();
+                            timings::record(
+                                TimingKind::Entry,
+                                &entry.id,
+                                deadline.duration_since(started),
+                                Some("timed out"),
+                            );
+                            config
+                                .timeout
+                                .show_timed_out
+                                .then_some(EntryValue::TimedOut)
+                        }
+                    }
+                }
+                (None, None) => None,
+            };
+            if let Some(value) = value {
+                report.entries.push(ReportEntry {
+                    id: entry.id.clone(),
+                    label_key: entry.label_key.clone(),
+                    value,
+                });
+            }
+        }
+        report
+    }
+}
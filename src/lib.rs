@@ -12,8 +12,14 @@
 )]
 #![deny(clippy::needless_return, clippy::str_to_string)]
 
+pub mod cache;
+pub mod cli;
 pub mod config;
+pub mod entries;
 pub mod logos;
+pub mod render;
 pub mod system;
+pub mod template;
+pub mod timings;
 pub mod translations;
 pub mod utils;
@@ -22,3 +22,31 @@ pub const ALPINE: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const ALPINE_SMALL: [&str; 2] = [
+    "24",
+    r#"⠀⠀⠀⠀[38;5;24m⢀⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⡀⠀⠀⠀⠀
+[38;5;24m⠀⠀⠀⢠⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡄⠀⠀⠀
+[38;5;24m⠀⠀⣠⣿⣿⣿⣿⣿⠟⢿⣿⣿⣿⣿⣿⣿⣿⣿⣄⠀⠀
+[38;5;24m⠀⣰⣿⣿⣿⣿⠟⠁⠀⠀⠙⠏⠁⠈⠻⣿⣿⣿⣿⣆⠀
+[38;5;24m⢴⣿⣿⣿⠟⠁⠀⢠⣾⣦⡀⠀⠀⢄⠀⠈⠻⣿⣿⣿⡦
+[38;5;24m⠈⢿⣿⣁⣀⣠⣀⣸⣿⣿⣿⣦⣀⣀⣱⣄⣀⣈⣿⡿⠁
+[38;5;24m⠀⠈⢻⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡟⠁⠀
+[38;5;24m⠀⠀⠀⢻⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡟⠀⠀⠀
+[38;5;24m⠀⠀⠀⠀⠹⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠏⠀⠀⠀⠀
+[38;5;24m⠀⠀⠀⠀⠀⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const ALPINE_ASCII: [&str; 2] = [
+    "24",
+    r#"[38;5;24m       .-----------.
+[38;5;24m      /             \
+[38;5;24m     /     /\  /\    \
+[38;5;24m    /     /  \/  \    \
+[38;5;24m   (     /   /\   \    )
+[38;5;24m    \   /   /  \   \  /
+[38;5;24m     \               /
+[38;5;24m      '-------------'
+"#,
+];
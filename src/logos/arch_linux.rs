@@ -23,3 +23,38 @@ pub const ARCH_LINUX: [&str; 2] = [
 [38;5;32m⠰⠋⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠛⠄
 "#,
 ];
+
+pub const ARCH_LINUX_SMALL: [&str; 2] = [
+    "32",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;32m⢀⣶⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⠀⣾⣿⣧⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⣼⣿⣿⣿⣧⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⣴⣝⣿⣿⣿⣿⣧⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⣼⣿⣿⣿⣿⣿⣿⣿⣦⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⣼⣿⣿⣿⣿⣿⣿⣿⣿⣿⣧⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⣼⣿⣿⣿⡿⠉⠉⠙⢿⣿⣿⣿⣧⠀⠀⠀⠀
+[38;5;32m⠀⠀⢀⣼⣿⣿⣿⣿⡇⠀⠀⠀⢸⣿⣿⣿⣿⣧⠀⠀⠀
+[38;5;32m⠀⢀⣾⣿⣿⣿⡿⠿⠃⠀⠀⠀⠘⠿⣿⣿⣿⣷⣤⠀⠀
+[38;5;32m⢀⣾⠿⠛⠉⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠉⠛⠿⣷⡀
+[38;5;32m⠈⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠁
+"#,
+];
+
+pub const ARCH_LINUX_ASCII: [&str; 2] = [
+    "32",
+    r#"[38;5;32m                  .
+[38;5;32m                 /#\
+[38;5;32m                /###\
+[38;5;32m               /#####\
+[38;5;32m              /#######\
+[38;5;32m             ;-_#######\
+[38;5;32m            /###########\
+[38;5;32m           /#####/"\#####\
+[38;5;32m          /####/     \####\
+[38;5;32m         /####|       |###-\
+[38;5;32m        /#####|       |#####\
+[38;5;32m       /####-'         '-####\
+[38;5;32m      /#-'                 '-#\
+[38;5;32m     /'                       '\
+"#,
+];
@@ -0,0 +1,46 @@
+/// The first character of the Braille Patterns block, the blank pattern.
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// The dots of the top half of a Braille cell (1, 2, 4 and 5), the others being in the bottom
+/// half (3, 6, 7 and 8).
+const TOP_DOTS: u32 = 0b0001_1011;
+
+/// Whether the terminal can only print ASCII: when the locale is not a UTF-8 one, on the Linux
+/// console and when `TERM` is `linux`, even if the output is piped.
+pub fn is_ascii_terminal() -> bool {
+    !supports_unicode::on(supports_unicode::Stream::Stdout)
+        || std::env::var("TERM").is_ok_and(|term| term == "linux")
+}
+
+/// An ASCII character drawing a Braille cell, from where and how many of its dots are raised.
+fn braille_to_ascii(dots: u32) -> char {
+    let top: u32 = (dots & TOP_DOTS).count_ones();
+    let bottom: u32 = (dots & !TOP_DOTS).count_ones();
+    match (top, bottom) {
+        (0, 0) => ' ',
+        (1..=2, 0) => '\'',
+        (_, 0) => '"',
+        (0, 1..=2) => '.',
+        (0, _) => 'o',
+        _ if top + bottom >= 7 => '#',
+        _ if top + bottom >= 5 => '*',
+        _ => ':',
+    }
+}
+
+/// Redraws the Braille characters of a logo with ASCII ones, the other characters that are not
+/// ASCII becoming `?`. ANSI escape codes and placeholders are kept.
+pub fn to_ascii(art: &str) -> String {
+    art.chars()
+        .map(|c| match c as u32 {
+            _ if c.is_ascii() => c,
+            code @ BRAILLE_BLANK..=0x28FF => braille_to_ascii(code - BRAILLE_BLANK),
+            _ => match c {
+                '─' | '━' => '-',
+                '│' | '┃' => '|',
+                '\u{a0}' => ' ',
+                _ => '?',
+            },
+        })
+        .collect()
+}
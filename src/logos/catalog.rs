@@ -0,0 +1,60 @@
+use crate::logos::{custom, Logo, LOGOS};
+use std::fmt;
+use std::path::PathBuf;
+
+/// A logo `--logo` accepts: its name, the other names it can be selected with, and the file it
+/// is read from when there is one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LogoEntry {
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub path: Option<PathBuf>,
+}
+
+impl fmt::Display for LogoEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.aliases.is_empty() {
+            write!(f, " ({})", self.aliases.join(", "))?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, ": {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// The builtin logos along with the files of `dirs` replacing them, then the logos only
+/// defined by a file, sorted by name. A file is listed once, from the first folder it is in.
+pub fn list_logos_in(dirs: &[PathBuf]) -> Vec<LogoEntry> {
+    let mut entries: Vec<LogoEntry> = LOGOS
+        .iter()
+        .map(|(name, aliases, ..)| LogoEntry {
+            name: (*name).to_owned(),
+            aliases: aliases.iter().map(|alias| (*alias).to_owned()).collect(),
+            path: custom::find_logo_file(name, dirs),
+        })
+        .collect();
+    for (name, path) in dirs.iter().flat_map(|dir| custom::logo_files(dir)) {
+        if !entries.iter().any(|entry| entry.name == name) {
+            entries.push(LogoEntry {
+                name,
+                aliases: Vec::new(),
+                path: Some(path),
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// The lines previewing `logo`: its large variant, then its small one below an empty line when
+/// it has one.
+pub fn preview(logo: &Logo) -> Vec<String> {
+    let mut lines: Vec<String> = logo.lines();
+    if logo.small.is_some() {
+        lines.push(String::default());
+        lines.extend(logo.clone().to_small().lines());
+    }
+    lines
+}
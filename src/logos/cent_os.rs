@@ -22,3 +22,33 @@ pub const CENT_OS: [&str; 2] = [
 [38;5;149m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⢿⡟⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const CENT_OS_SMALL: [&str; 2] = [
+    "215",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀[38;5;215m⢀⣴⣿⣦⡀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;215m⠀⠀⠀[38;5;149m⣤⣤⣤⢄⣤⣭⣭[38;5;215m⣿[38;5;126m⢩⣭⣤⡀⢤⣤⣤⠀⠀⠀
+[38;5;126m⠀⠀⠀[38;5;149m⡿⢛⢧⡻⣿⣿⣿[38;5;215m⣿[38;5;126m⢸⣿⡿⢋⡴⡛⢿⠀⠀⠀
+[38;5;126m⠀⠀⠀[38;5;149m⣴⣿⣷⣝⢦⡙⠿[38;5;215m⣿[38;5;126m⠸⢋⠔⣡⣾⣿⣦⠀⠀⠀
+[38;5;126m⢀⣴⣇[38;5;149m⣛⣛⣛⣛⣓⣉⠂[38;5;215m⠉[38;5;126m⠐⣁⣚⣛⣛⣛⣛[38;5;24m⣸⣦⡀
+[38;5;126m⠈⠻⡏[38;5;24m⣭⣭⣭⣭⠭⢉⠄[38;5;149m⣀[38;5;215m⠠⣍⢭⣭⣭⣭⣭[38;5;24m⠹⠟⠁
+[38;5;24m⠀⠀⠀⠻⣿⠟⢁⠔⣡⡆[38;5;149m⣿[38;5;215m⣶⣌⡳⣝⢿⣿⠟⠀⠀⠀
+[38;5;215m⠀⠀⠀[38;5;24m⣶⣤⠊⣡⣾⣿⡇[38;5;149m⣿[38;5;215m⣿⣿⣿⣮⢳⣵⣿⠀⠀⠀
+[38;5;215m⠀⠀⠀[38;5;24m⠛⠛⠓⠈⠛⣛⣃[38;5;149m⣿[38;5;215m⣛⣛⠛⠑⠛⠛⠛⠀⠀⠀
+[38;5;215m⠀⠀⠀⠀⠀⠀⠀⠀[38;5;149m⠈⠻⣿⠟⠁⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const CENT_OS_ASCII: [&str; 2] = [
+    "215",
+    r#"[38;5;215m  .-------. [38;5;149m.-------.
+[38;5;215m  | \     | [38;5;149m|     / |
+[38;5;215m  |   \   | [38;5;149m|   /   |
+[38;5;215m  |     \ | [38;5;149m| /     |
+[38;5;215m  '-------' [38;5;149m'-------'
+[38;5;126m  .-------. [38;5;24m.-------.
+[38;5;126m  |     / | [38;5;24m| \     |
+[38;5;126m  |   /   | [38;5;24m|   \   |
+[38;5;126m  | /     | [38;5;24m|     \ |
+[38;5;126m  '-------' [38;5;24m'-------'
+"#,
+];
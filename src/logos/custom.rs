@@ -0,0 +1,133 @@
+use crate::config::layers::system_config_path;
+use crate::logos::{normalize_name, placeholder_index, Logo, LogoColor, PLACEHOLDERS};
+use crate::system::environment::Environment;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// The folders logo files are looked for in, in order: `logos` next to `config_path` when it is
+/// given, then next to the user configuration file of `environment`, then next to the system
+/// one.
+pub fn logo_dirs(environment: &Environment, config_path: Option<&Path>) -> Vec<PathBuf> {
+    let config_dir: Option<PathBuf> =
+        config_path.and_then(|config_path| Some(config_path.parent()?.to_path_buf()));
+    let user_dir: PathBuf = environment.path(environment.config_dir().join("afetch"));
+    let system_dir: Option<PathBuf> =
+        system_config_path().and_then(|config_path| Some(environment.path(config_path.parent()?)));
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    for dir in [config_dir, Some(user_dir), system_dir]
+        .into_iter()
+        .flatten()
+    {
+        let dir: PathBuf = dir.join("logos");
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+    }
+    dirs
+}
+
+/// The logo files of `dir` along with their names, e.g. `Arch Linux.txt` is named `archlinux`.
+pub fn logo_files(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut files: Vec<(String, PathBuf)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path: PathBuf = entry.ok()?.path();
+            if path.extension()? != "txt" || !path.is_file() {
+                return None;
+            }
+            Some((normalize_name(path.file_stem()?.to_str()?), path))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// The first file of `dirs` named `name`, once normalized.
+pub fn find_logo_file(name: &str, dirs: &[PathBuf]) -> Option<PathBuf> {
+    let name: String = normalize_name(name);
+    dirs.iter().find_map(|dir| {
+        logo_files(dir)
+            .into_iter()
+            .find(|(file_name, _)| *file_name == name)
+            .map(|(_, path)| path)
+    })
+}
+
+pub fn read_logo_file(path: &Path) -> Result<Logo, String> {
+    let content: String =
+        std::fs::read_to_string(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let name: String = path
+        .file_stem()
+        .map(|name| normalize_name(&name.to_string_lossy()))
+        .unwrap_or_default();
+    let mut logo: Logo =
+        parse_logo(&name, &content).map_err(|error| format!("{}: {}", path.display(), error))?;
+    logo.path = Some(path.to_path_buf());
+    Ok(logo)
+}
+
+/// Parses a logo file: the art, optionally preceded by a header between two `---` lines
+/// setting the colors of its `${c1}`..`${c6}` placeholders, `color` being the same as `c1`.
+///
+/// ```text
+/// ---
+/// c1: 32
+/// c2: "#1793d1"
+/// ---
+/// ${c1}   /\
+/// ${c1}  /  \
+/// ${c2} /\   \
+/// ```
+///
+/// The art starts in the `c1` color, so that a logo without placeholders is drawn in it.
+pub fn parse_logo(name: &str, content: &str) -> Result<Logo, String> {
+    let content: String = content.replace("\r\n", "\n");
+    let (header, art): (&str, &str) = match content.strip_prefix("---\n") {
+        Some(rest) => rest
+            .split_once("\n---\n")
+            .or_else(|| rest.strip_suffix("\n---").map(|header| (header, "")))
+            .ok_or_else(|| "the header is never closed by a `---` line".to_owned())?,
+        None => ("", content.as_str()),
+    };
+
+    let mut colors: BTreeMap<String, LogoColor> = BTreeMap::new();
+    for (index, line) in header.lines().enumerate() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value): (&str, &str) = line
+            .split_once(':')
+            .ok_or_else(|| format!("line {}: expected `key: value`", index + 2))?;
+        // The values can be quoted, as they would be in YAML.
+        let value: &str = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        let key: &str = match key.trim() {
+            "color" => "c1",
+            key if placeholder_index(key).is_some() => key,
+            key => {
+                return Err(format!(
+                    "line {}: unknown key `{}`, expected `color` or `c1` to `c{}`",
+                    index + 2,
+                    key,
+                    PLACEHOLDERS
+                ))
+            }
+        };
+        let color: LogoColor = value
+            .parse()
+            .map_err(|error| format!("line {}: `{}`: {}", index + 2, key, error))?;
+        colors.insert(key.to_owned(), color);
+    }
+
+    let logo: Logo = Logo {
+        name: name.to_owned(),
+        colors: vec![LogoColor::default()],
+        art: art.trim_end_matches('\n').to_owned(),
+        path: None,
+        small: None,
+        ascii: None,
+        graphic: None,
+    };
+    Ok(logo.with_colors(&colors))
+}
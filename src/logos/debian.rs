@@ -26,3 +26,35 @@ pub const DEBIAN: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;161m⠉⠉⠓⠒⠂⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const DEBIAN_SMALL: [&str; 2] = [
+    "161",
+    r#"⠀⠀⠀⠀⠀⠀[38;5;161m⣀⣤⣤⣤⣶⣤⣤⣀⣀⠀⠀⠀⠀⠀
+[38;5;161m⠀⠀⠀⢀⣴⣾⣿⡿⠟⠛⠛⠛⠛⠻⢿⣿⣦⣄⠀⠀
+[38;5;161m⠀⠀⣰⣿⠿⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⢿⣿⣦⠀
+[38;5;161m⠀⣴⣿⠁⠀⠀⠀⠀⠀⣀⠤⠦⠤⠀⠀⠀⠈⣿⡏⠀
+[38;5;161m⢰⣿⠃⠀⠀⠀⠀⠀⡜⠁⠀⠀⠀⠀⠀⠀⠀⣿⣷⠀
+[38;5;161m⢸⣿⠀⠀⠀⠀⠀⢸⡇⠀⠀⠀⠀⠀⠀⠀⠀⣿⠇⠀
+[38;5;161m⢸⣿⠀⠀⠀⠀⠀⠀⠻⣄⠀⠀⠀⠀⠀⢀⡼⠋⠀⠀
+[38;5;161m⠀⣿⣆⠀⠀⠀⠀⠀⠀⠈⠓⠶⠶⠶⠚⠋⠀⠀⠀⠀
+[38;5;161m⠀⠘⣿⣧⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;161m⠀⠀⠈⢿⣦⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;161m⠀⠀⠀⠀⠙⠷⣤⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;161m⠀⠀⠀⠀⠀⠀⠈⠙⠳⠤⠄⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const DEBIAN_ASCII: [&str; 2] = [
+    "161",
+    r#"[38;5;161m          _.-''''-._
+[38;5;161m        .'   _.._   '.
+[38;5;161m       /   .'    '.   \
+[38;5;161m      |   /   .-.  |   |
+[38;5;161m      |   |  (     /  .'
+[38;5;161m       \   \  '-..'  /
+[38;5;161m        '.  '-.__.-'`
+[38;5;161m          '-.
+[38;5;161m             '-._
+[38;5;161m                 '
+"#,
+];
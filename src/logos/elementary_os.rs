@@ -21,3 +21,32 @@ pub const ELEMENTARY_OS: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;74m⠀⠉⠙⠛⠛⠿⠿⠿⠿⠟⠛⠛⠉⠁            
 "#,
 ];
+
+pub const ELEMENTARY_OS_SMALL: [&str; 2] = [
+    "74",
+    r#"⠀⠀⠀⠀[38;5;74m⣀⣤⣶⣶⣾⣿⣶⣶⣤⣀⠀⠀⠀⠀⠀
+[38;5;74m⠀⠀⣠⣾⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿[38;5;74m⣿⣿⣷⣦⠀⠀⠀
+[38;5;74m⢀⣾⣿⣿[38;5;189m⣿[38;5;74m⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿[38;5;189m⣿[38;5;74m⣿⣷⡀⠀
+[38;5;74m⣾⣿[38;5;189m⣿[38;5;74m⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣷⠀
+[38;5;74m⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⡆
+[38;5;74m⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⣿⣿⠁
+[38;5;74m⠹⣿[38;5;189m⣿[38;5;74m⣿⣿[38;5;189m⣿⣿⣿[38;5;74m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;74m⣿⣿[38;5;189m⣿[38;5;74m⠏⠀
+[38;5;74m⠀⠙⢿[38;5;189m⣿[38;5;74m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;74m⣿⣿⣿⣿⣿[38;5;189m⣿[38;5;74m⠋⠀⠀
+[38;5;74m⠀⠀⠀⠙⠿[38;5;189m⣿⣿[38;5;74m⣿⣿⣿⣿⣿[38;5;189m⣿⠿[38;5;74m⠋⠁⠀⠀⠀
+[38;5;74m⠀⠀⠀⠀⠀⠀⠉⠉⠛⠛⠉⠉⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const ELEMENTARY_OS_ASCII: [&str; 2] = [
+    "74",
+    r#"[38;5;74m        .-'''''-.
+[38;5;74m      .'   __    '.
+[38;5;74m     /   .'  '.    \
+[38;5;74m    |   /     /     |
+[38;5;74m    |  |   .-'      |
+[38;5;74m    |   \.'      .' |
+[38;5;74m     \  /'.___.-'  /
+[38;5;74m      '.         .'
+[38;5;74m        '-.....-'
+"#,
+];
@@ -18,3 +18,30 @@ pub const ENDEAVOUR: [&str; 2] = [
 ⠀⠀[38;5;105m⠐⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠉⠉⠉⠉⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const ENDEAVOUR_SMALL: [&str; 2] = [
+    "97",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;210m⣠[38;5;97m⣴⣄⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;97m⠀⠀⠀⠀⠀⠀⠀⠀[38;5;210m⢀⣴⣿[38;5;97m⣿⣿⣷[38;5;105m⣄⠀⠀⠀⠀⠀⠀
+[38;5;105m⠀⠀⠀⠀⠀⠀[38;5;210m⢀⣴⣿[38;5;97m⣿⣿⣿⣿⣿⣿[38;5;105m⣷⣄⠀⠀⠀⠀
+[38;5;105m⠀⠀⠀⠀[38;5;210m⢀⣴⣿⣿[38;5;97m⣿⣿⣿⣿⣿⣿⣿⣿[38;5;105m⣿⣧⡀⠀⠀
+[38;5;105m⠀⠀⠀[38;5;210m⣰⣿⣿⣿[38;5;97m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;105m⣿⡄⠀
+[38;5;105m⠀[38;5;210m⣠⣾⣿⣿[38;5;97m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;105m⣿⣿⡀
+[38;5;210m⠚⠿⢿⣿[38;5;97m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;105m⣿⡿⠁
+[38;5;105m⠀⠰⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠛⠛⠉⠀⠀
+"#,
+];
+
+pub const ENDEAVOUR_ASCII: [&str; 2] = [
+    "97",
+    r#"[38;5;210m              /\
+[38;5;210m            //  \[38;5;133m\
+[38;5;210m          //     \[38;5;133m \
+[38;5;210m        //        \[38;5;133m  \
+[38;5;210m      //          /[38;5;133m   \
+[38;5;210m     //          /[38;5;133m    |
+[38;5;210m   //          /[38;5;133m     /
+[38;5;210m  '----------'[38;5;133m     /
+[38;5;105m    '----------------'
+"#,
+];
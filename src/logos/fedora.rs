@@ -22,3 +22,32 @@ pub const FEDORA: [&str; 2] = [
 ⠀[38;5;39m⠙⠻⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠿⠿⠿⠛⠛⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const FEDORA_SMALL: [&str; 2] = [
+    "39",
+    r#"⠀⠀⠀⠀[38;5;39m⢀⣠⣴⣶⣿⣿⣿⣿⣷⣶⣦⣄⡀⠀⠀⠀⠀
+[38;5;39m⠀⠀⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣦⡀⠀⠀
+[38;5;39m⠀⣰⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿⣿⣿⣆⠀
+[38;5;39m⢰⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿⣿⡆
+[38;5;39m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿[38;5;189m⣿[38;5;39m⣿⣿⣿⣿⣿
+[38;5;39m⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;39m⣿[38;5;189m⣿⣿⣿⣿[38;5;39m⣿⣿⣿⣿⣿⣿⣿
+[38;5;39m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿⣿⣿⣿⣿⣿⠇
+[38;5;39m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;39m⣿⣿⣿⣿[38;5;189m⣿[38;5;39m⣿⣿⣿⣿⣿⣿⣿⣿⠏⠀
+[38;5;39m⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿[38;5;39m⣿⣿⣿⣿⣿⣿⣿⠟⠁⠀⠀
+[38;5;39m⠹⢿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠿⠟⠋⠁⠀⠀⠀⠀
+"#,
+];
+
+pub const FEDORA_ASCII: [&str; 2] = [
+    "39",
+    r#"[38;5;39m          .-''''''-.
+[38;5;39m        .'          '.
+[38;5;39m       /      [38;5;189m.--.[38;5;39m    \
+[38;5;39m      |       [38;5;189m|[38;5;39m        |
+[38;5;39m      |    [38;5;189m.--+--[38;5;39m      |
+[38;5;39m      |       [38;5;189m|[38;5;39m        |
+[38;5;39m      |   [38;5;189m'--'[38;5;39m        .'
+[38;5;39m       \             .'
+[38;5;39m        '-.________.-'
+"#,
+];
@@ -23,3 +23,32 @@ pub const FREEBSD: [&str; 2] = [
 [38;5;124m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠙⠛⠛⠿⠿⠿⠿⠿⠛⠛⠋⠉⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const FREEBSD_SMALL: [&str; 2] = [
+    "124",
+    r#"[38;5;124m⣾⣷⣶⣤⣀⠀⢀⣠⣤⣶⣶⣶⣦⣤⣀⠀⢀⣤⣴⣶⣶
+[38;5;124m⠹⣿⡿⢋⣴⣾⣿⣿⣿⣿⣿⣿⣿⡏⣵⣾⣿⣿⣿⣿⠏
+[38;5;124m⠀⠙⣰⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣷⡙⣿⣿⣿⣿⠏⠀
+[38;5;124m⠀⣰⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣮⣙⠻⠋⣾⡀
+[38;5;124m⢀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣧
+[38;5;124m⢸⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+[38;5;124m⠀⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇
+[38;5;124m⠀⠘⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡟⠀
+[38;5;124m⠀⠀⠈⢿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠟⠀⠀
+[38;5;124m⠀⠀⠀⠀⠉⠻⢿⣿⣿⣿⣿⣿⣿⣿⣿⠿⠛⠁⠀⠀⠀
+[38;5;124m⠀⠀⠀⠀⠀⠀⠀⠈⠉⠙⠛⠛⠉⠉⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const FREEBSD_ASCII: [&str; 2] = [
+    "124",
+    r#"[38;5;124m  /\                /\
+[38;5;124m (  '.  .-''''-.  .'  )
+[38;5;124m  '.  '/        \'  .'
+[38;5;124m    '-|          |-'
+[38;5;124m      |          |
+[38;5;124m      |          |
+[38;5;124m       \        /
+[38;5;124m        '-....-'
+"#,
+];
@@ -23,3 +23,34 @@ pub const GENTOO: [&str; 2] = [
 ⠀⠀⠀[38;5;60m⠙⠻⢿⣿⣿⣿⣿⣿⣿⠿⠿⠛⠋⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const GENTOO_SMALL: [&str; 2] = [
+    "104",
+    r#"⠀⠀⠀[38;5;105m⢀⣤⣶[38;5;15m⣿[38;5;105m⣿⣿⣿⣿[38;5;189m⣷[38;5;105m⣦⣄⠀⠀⠀⠀⠀⠀⠀
+[38;5;105m⠀⢀⣴⣿[38;5;15m⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;147m⣿[38;5;105m⣿[38;5;147m⣦[38;5;105m⣀⠀⠀⠀⠀
+[38;5;105m⢰⣿[38;5;15m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;147m⣿[38;5;105m⣿[38;5;147m⣷[38;5;105m⣄⠀⠀
+[38;5;105m⢻⣿[38;5;15m⣿⣿⣿⣿⣿⣿[38;5;147m⣿[38;5;104m⣏[38;5;15m⣉[38;5;104m⣿[38;5;189m⣿⣿⣿[38;5;147m⣿⣿⣿⣿[38;5;105m⣷⡄
+[38;5;105m⠀⠙⠿[38;5;147m⣿[38;5;15m⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;147m⣿⣿⣿⣿⣿[38;5;105m⣿
+[38;5;105m⠀⠀⠀[38;5;15m⢈⣹[38;5;105m⣿[38;5;15m⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;147m⣿⣿⣿⣿⣿[38;5;15m⣿[38;5;146m⡟
+[38;5;146m⠀[38;5;15m⢀⣴⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;147m⣿⣿⣿⣿[38;5;15m⣿⡿[38;5;103m⠋⠀
+[38;5;15m⣰⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿[38;5;147m⣿⣿[38;5;15m⣿⣿[38;5;103m⠟⠉⠀⠀⠀
+[38;5;15m⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿[38;5;15m⣿⣿[38;5;103m⠿[38;5;60m⠋⠀⠀⠀⠀⠀⠀
+[38;5;60m⠻[38;5;15m⣿⣿[38;5;189m⣿⣿⣿⣿[38;5;15m⣿⣿⡿[38;5;60m⠟⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;60m⠀⠈⠙⠛⠛⠛⠛⠉⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const GENTOO_ASCII: [&str; 2] = [
+    "104",
+    r#"[38;5;104m        .-----.
+[38;5;104m      .'       '.
+[38;5;104m     /    .-.    '.
+[38;5;104m    |    (   )     '.
+[38;5;104m     \    '-'        \
+[38;5;104m      '.            .'
+[38;5;104m       /         .-'
+[38;5;104m      /       .-'
+[38;5;104m     |     .-'
+[38;5;104m      '--''
+"#,
+];
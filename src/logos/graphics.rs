@@ -0,0 +1,240 @@
+use crate::config::PictureProtocol;
+use crate::logos::picture::{accent_color, resize, scale_picture};
+use crate::logos::{normalize_name, Logo, LogoColor};
+use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+use std::collections::BTreeSet;
+use std::io::Cursor;
+use std::path::Path;
+
+/// The size of a cell in pixels when the terminal does not tell it.
+const DEFAULT_CELL_SIZE: (u32, u32) = (10, 20);
+
+/// The largest piece of data of a Kitty escape code.
+const KITTY_CHUNK: usize = 4096;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// A protocol terminals draw pictures with, pixel by pixel.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Iterm,
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// The protocol set with `picture_protocol`, or with `auto` the one the terminal is known to
+    /// support from the variables of `env`. `None` when the picture is drawn with half blocks
+    /// instead.
+    pub fn select(protocol: PictureProtocol, env: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        match protocol {
+            PictureProtocol::Auto => Self::detect(env),
+            PictureProtocol::Kitty => Some(Self::Kitty),
+            PictureProtocol::Iterm => Some(Self::Iterm),
+            PictureProtocol::Sixel => Some(Self::Sixel),
+            PictureProtocol::Blocks => None,
+        }
+    }
+
+    fn detect(env: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term: String = env("TERM").unwrap_or_default();
+        let program: String = env("TERM_PROGRAM").unwrap_or_default();
+        if env("KITTY_WINDOW_ID").is_some()
+            || ["xterm-kitty", "xterm-ghostty"].contains(&term.as_str())
+            || program == "ghostty"
+        {
+            Some(Self::Kitty)
+        } else if ["iTerm.app", "WezTerm"].contains(&program.as_str())
+            || env("LC_TERMINAL").as_deref() == Some("iTerm2")
+        {
+            Some(Self::Iterm)
+        } else if term.contains("sixel")
+            || ["foot", "foot-extra", "mlterm", "yaft-256color"].contains(&term.as_str())
+            || program == "contour"
+        {
+            Some(Self::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+/// The size of a cell of the terminal in pixels, from the size of its window.
+#[cfg(unix)]
+pub fn cell_size() -> (u32, u32) {
+    // SAFETY: `winsize` is plain data and `TIOCGWINSZ` only writes to it.
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let result: libc::c_int =
+        unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    if result == 0 && size.ws_col > 0 && size.ws_row > 0 && size.ws_xpixel > 0 && size.ws_ypixel > 0
+    {
+        (
+            u32::from(size.ws_xpixel / size.ws_col),
+            u32::from(size.ws_ypixel / size.ws_row),
+        )
+    } else {
+        DEFAULT_CELL_SIZE
+    }
+}
+
+#[cfg(not(unix))]
+pub fn cell_size() -> (u32, u32) {
+    DEFAULT_CELL_SIZE
+}
+
+/// Reads the picture at `path` to draw it with `protocol`, `width` columns wide, or less so
+/// that it is at most `height` rows high, a cell being `cell` pixels. The art of the logo is
+/// blank, taking the room of the picture, which is drawn over it once printed.
+pub fn graphics_logo(
+    path: &Path,
+    protocol: GraphicsProtocol,
+    cell: (u32, u32),
+    width: usize,
+    height: Option<usize>,
+) -> Result<Logo, String> {
+    let picture: DynamicImage =
+        image::open(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let (pixel_width, pixel_height): (u32, u32) =
+        scale_picture((picture.width(), picture.height()), cell, width, height);
+    let pixels: RgbaImage = resize(&picture, pixel_width, pixel_height);
+    let columns: u32 = pixel_width.div_ceil(cell.0);
+    let rows: u32 = pixel_height.div_ceil(cell.1);
+
+    let (r, g, b): (u8, u8, u8) = accent_color(&pixels, |x, y| pixels.get_pixel(x, y)[3] >= 128);
+    Ok(Logo {
+        name: path
+            .file_stem()
+            .map(|name| normalize_name(&name.to_string_lossy()))
+            .unwrap_or_default(),
+        colors: vec![LogoColor::Rgb(r, g, b)],
+        art: vec![" ".repeat(columns as usize); rows as usize].join("\n"),
+        path: Some(path.to_path_buf()),
+        small: None,
+        ascii: None,
+        graphic: Some(encode(&pixels, protocol, columns, rows)?),
+    })
+}
+
+/// The escape code drawing `pixels` with `protocol` from the cursor, over `columns` and `rows`
+/// cells.
+pub fn encode(
+    pixels: &RgbaImage,
+    protocol: GraphicsProtocol,
+    columns: u32,
+    rows: u32,
+) -> Result<String, String> {
+    if protocol == GraphicsProtocol::Sixel {
+        return Ok(sixel(pixels));
+    }
+    let mut png: Vec<u8> = Vec::new();
+    DynamicImage::ImageRgba8(pixels.clone())
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|error| error.to_string())?;
+    let data: String = base64(&png);
+
+    if protocol == GraphicsProtocol::Iterm {
+        return Ok(format!(
+            "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+            png.len(),
+            columns,
+            rows,
+            data
+        ));
+    }
+    // The data is sent in chunks, `m=1` telling that more are coming.
+    let chunks: Vec<&[u8]> = data.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut escape: String = String::default();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let more: u8 = u8::from(index + 1 < chunks.len());
+        let chunk: &str = std::str::from_utf8(chunk).unwrap_or_default();
+        if index == 0 {
+            escape.push_str(&format!(
+                "\x1b_Ga=T,f=100,q=2,c={},r={},m={};{}\x1b\\",
+                columns, rows, more, chunk
+            ));
+        } else {
+            escape.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    Ok(escape)
+}
+
+/// Encodes `pixels` as Sixel, the colors being reduced to the 6x6x6 cube of the 256 colors
+/// palette and the transparent pixels being left as they are.
+fn sixel(pixels: &RgbaImage) -> String {
+    let (width, height): (u32, u32) = pixels.dimensions();
+    let register = |pixel: &Rgba<u8>| {
+        (pixel[3] >= 128).then(|| {
+            (0..3).fold(0, |register: usize, channel: usize| {
+                register * 6 + (pixel[channel] as usize * 5 + 127) / 255
+            })
+        })
+    };
+    let registers: BTreeSet<usize> = pixels.pixels().filter_map(register).collect();
+
+    let mut escape: String = format!("\x1bP0;1;0q\"1;1;{};{}", width, height);
+    for index in &registers {
+        let level = |value: usize| value * 100 / 5;
+        escape.push_str(&format!(
+            "#{};2;{};{};{}",
+            index,
+            level(index / 36),
+            level(index / 6 % 6),
+            level(index % 6)
+        ));
+    }
+    // Each line of sixels draws 6 rows of pixels, color after color.
+    for top in (0..height).step_by(6) {
+        let mut first: bool = true;
+        for index in &registers {
+            let sixels: Vec<u8> = (0..width)
+                .map(|x| {
+                    (0..6)
+                        .filter(|dy| {
+                            top + dy < height
+                                && register(pixels.get_pixel(x, top + dy)) == Some(*index)
+                        })
+                        .fold(0, |sixel: u8, dy| sixel | 1 << dy)
+                })
+                .collect();
+            if sixels.iter().all(|sixel| *sixel == 0) {
+                continue;
+            }
+            if !first {
+                escape.push('$');
+            }
+            first = false;
+            escape.push_str(&format!("#{}", index));
+            for run in sixels.chunk_by(|a, b| a == b) {
+                let character: char = char::from(63 + run[0]);
+                if run.len() > 3 {
+                    escape.push_str(&format!("!{}{}", run.len(), character));
+                } else {
+                    escape.extend(std::iter::repeat_n(character, run.len()));
+                }
+            }
+        }
+        escape.push('-');
+    }
+    escape.push_str("\x1b\\");
+    escape
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded: String = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group: u32 = chunk.iter().enumerate().fold(0, |group, (index, byte)| {
+            group | u32::from(*byte) << (16 - 8 * index)
+        });
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(char::from(
+                    BASE64[(group >> (18 - 6 * index) & 63) as usize],
+                ));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
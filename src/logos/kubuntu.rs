@@ -23,3 +23,32 @@ pub const KUBUNTU: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;32m ⠀⠉⠛⠻⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠿⠟⠛⠉⠀ ⠀ ⠀⠀⠀⠀⠀⠀⠀ 
 "#,
 ];
+
+pub const KUBUNTU_SMALL: [&str; 2] = [
+    "32",
+    r#"⠀⠀⠀⠀[38;5;32m⢀⣤⣶⣾⣿⣿⣿⣿⣿⣷⣶⣤⡀⠀⠀⠀⠀
+[38;5;32m⠀⠀⢠⣶⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⡄⠀⠀
+[38;5;32m⠀⣴⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;32m⣿[38;5;189m⣿⣿⣿[38;5;32m⣿⣿⣿⣦⠀
+[38;5;32m⣸⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;32m⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;32m⣿⣿⣿⣿⣿⣇
+[38;5;32m⣿⣿⣿⣿⣿[38;5;189m⣿[38;5;32m⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿[38;5;32m⣿⣿
+[38;5;32m⣿⣿[38;5;189m⣿⣿⣿⣿[38;5;32m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
+[38;5;32m⢿⣿⣿⣿⣿[38;5;189m⣿[38;5;32m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;32m⣿⡿
+[38;5;32m⠘⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;32m⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;32m⣿⣿⣿⣿⠃
+[38;5;32m⠀⠈⢻⣿⣿[38;5;189m⣿[38;5;32m⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿[38;5;32m⣿⣿⣿⡟⠁⠀
+[38;5;32m⠀⠀⠀⠉⠻⣿⣿⣿[38;5;189m⣿⣿[38;5;32m⣿⣿⣿⣿[38;5;189m⣿[38;5;32m⣿⠟⠉⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠉⠙⠛⠛⠛⠛⠛⠋⠉⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const KUBUNTU_ASCII: [&str; 2] = [
+    "32",
+    r#"[38;5;32m        .-''''-.
+[38;5;32m      .'  [38;5;189m|  /[38;5;32m  '.
+[38;5;32m     /    [38;5;189m| /[38;5;32m     \
+[38;5;32m    |     [38;5;189m|<[38;5;32m       |
+[38;5;32m    |     [38;5;189m| \[38;5;32m      |
+[38;5;32m     \    [38;5;189m|  \[38;5;32m    /
+[38;5;32m      '.        .'
+[38;5;32m        '-....-'
+"#,
+];
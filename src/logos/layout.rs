@@ -0,0 +1,87 @@
+use crate::config::LogoPosition;
+use crate::logos::{display_width, Logo};
+
+/// Lays out the lines of `logo` and `infos` as they are printed: side by side or one above the
+/// other depending on `position`, with `padding` spaces before the first column and between
+/// the logo and the information. A blank line separates them when they are stacked.
+pub fn arrange(
+    logo: &Logo,
+    infos: &[String],
+    position: LogoPosition,
+    padding: usize,
+) -> Vec<String> {
+    let margin: String = " ".repeat(padding);
+    let logo_lines: Vec<String> = logo.lines();
+    let logo_widths: Vec<usize> = logo.widths();
+    let logo_width: usize = logo_widths.iter().max().copied().unwrap_or_default();
+    // The lines of logo files can have different widths.
+    let logo_line = |i: usize| match logo_lines.get(i) {
+        Some(line) => format!("{}{}", line, " ".repeat(logo_width - logo_widths[i])),
+        None => " ".repeat(logo_width),
+    };
+
+    match position {
+        LogoPosition::Left => (0..logo_lines.len().max(infos.len()))
+            .map(|i| {
+                let info: &str = infos.get(i).map_or("", String::as_str);
+                format!("{}{}{}{}", margin, logo_line(i), margin, info)
+            })
+            .collect(),
+        LogoPosition::Right => {
+            let infos_width: usize = infos
+                .iter()
+                .map(|info| display_width(info))
+                .max()
+                .unwrap_or_default();
+            (0..logo_lines.len().max(infos.len()))
+                .map(|i| {
+                    let info: &str = infos.get(i).map_or("", String::as_str);
+                    let info_padding: String = " ".repeat(infos_width - display_width(info));
+                    if i < logo_lines.len() {
+                        format!(
+                            "{}{}{}{}{}",
+                            margin,
+                            info,
+                            info_padding,
+                            margin,
+                            logo_line(i)
+                        )
+                    } else {
+                        format!("{}{}", margin, info)
+                    }
+                })
+                .collect()
+        }
+        LogoPosition::Top | LogoPosition::Bottom => {
+            let logo_block = logo_lines.iter().map(|line| format!("{}{}", margin, line));
+            let infos_block = infos.iter().map(|info| format!("{}{}", margin, info));
+            if position == LogoPosition::Top {
+                logo_block
+                    .chain(std::iter::once(String::default()))
+                    .chain(infos_block)
+                    .collect()
+            } else {
+                infos_block
+                    .chain(std::iter::once(String::default()))
+                    .chain(logo_block)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The line and the column of the first character of the logo in the lines of [`arrange`].
+pub fn logo_origin(infos: &[String], position: LogoPosition, padding: usize) -> (usize, usize) {
+    match position {
+        LogoPosition::Left | LogoPosition::Top => (0, padding),
+        LogoPosition::Right => {
+            let infos_width: usize = infos
+                .iter()
+                .map(|info| display_width(info))
+                .max()
+                .unwrap_or_default();
+            (0, padding * 2 + infos_width)
+        }
+        LogoPosition::Bottom => (infos.len() + 1, padding),
+    }
+}
@@ -27,3 +27,33 @@ pub const LINUX: [&str; 2] = [
     
 "#,
 ];
+
+pub const LINUX_SMALL: [&str; 2] = [
+    "220",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;16m⣠⣶⣶⣶⣦⡀⠀⠀⠀⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⣿⣿⣿⣿⣿⣷⠀⠀⠀⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸⣿⣾⣿⣿⣷⣿⠀⠀⠀⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸[38;5;220m⣿⣿⣿⡿[38;5;16m⣻⣿⡄⠀⠀⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⠀⠀⢠[38;5;231m⣿⣷[38;5;220m⣿⣿[38;5;231m⣾⣿[38;5;16m⣿⣷⡀⠀⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⠀⣴⣿[38;5;231m⣿⣿⣿⣿⣿⣿⣿[38;5;16m⣿⣿⣆⠀⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⠀⣾⣿[38;5;231m⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;16m⣿⣿⣧⠀⠀⠀
+[38;5;16m⠀⠀⠀⠀⠀⢸⣿[38;5;231m⢿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;16m⣿⣿⣿⠀⠀⠀
+[38;5;16m⠀⠀⠀[38;5;220m⣀⣤⣾⣿⣞[38;5;231m⢿⣿⣿⣿⣿⣿⣿⣿⢏[38;5;16m⢿⡿[38;5;220m⣵⣦⠀⠀
+[38;5;220m⠀⠀⠈⣿⣿⣿⣿⣿⣷[38;5;231m⣽⣿⣿⣿⣿⣿⣿[38;5;220m⣿⣿⣿⣿⣿⣦⡀
+[38;5;220m⠀⠀⢸⣿⣿⣿⣿⣿⣿⣿[38;5;231m⡽⣿⣿⣿⡿[38;5;220m⣽⣿⣿⣿⣿⠿⠛⠁
+[38;5;220m⠀⠀⠀⠈⠉⠙⠛⠻⠿⠟⠁⠀⠀⠀⠀⠙⠿⠿⠟⠁⠀⠀⠀
+[38;5;220m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const LINUX_ASCII: [&str; 2] = [
+    "220",
+    r#"[38;5;16m        .--.
+[38;5;16m       |[38;5;231mo[38;5;16m_[38;5;231mo[38;5;16m |
+[38;5;16m       |[38;5;220m:_/[38;5;16m |
+[38;5;16m      //[38;5;231m   \ [38;5;16m\
+[38;5;16m     (|[38;5;231m     |[38;5;16m )
+[38;5;16m    /'\[38;5;231m_   _/[38;5;16m`\
+[38;5;220m    \___)[38;5;16m=[38;5;220m(___/
+"#,
+];
@@ -17,3 +17,28 @@ pub const LINUX_MINT: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;35m⠈⠙⠛⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠿⠋⠀
 "#,
 ];
+
+pub const LINUX_MINT_SMALL: [&str; 2] = [
+    "35",
+    r#"[38;5;35m⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣶⣤⡀⠀⠀
+[38;5;35m⠛⠻⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣦⠀
+[38;5;35m⠀⠀⣿⣿[38;5;189m⣿[38;5;35m⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿[38;5;35m⣿⡆
+[38;5;35m⠀⠀⣿⣿[38;5;189m⣿[38;5;35m⣿⣿[38;5;189m⣿⣿[38;5;35m⣿[38;5;189m⣿⣿[38;5;35m⣿⣿[38;5;189m⣿⣿[38;5;35m⡇
+[38;5;35m⠀⠀⣿⣿[38;5;189m⣿[38;5;35m⣿⣿[38;5;189m⣿⣿[38;5;35m⣿[38;5;189m⣿⣿[38;5;35m⣿⣿[38;5;189m⣿⣿[38;5;35m⡇
+[38;5;35m⠀⠀⢻⣿[38;5;189m⣿⣿[38;5;35m⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;35m⡇
+[38;5;35m⠀⠀⠀⠻⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;35m⣿⡇
+[38;5;35m⠀⠀⠀⠀⠀⠉⠛⠛⠛⠛⠛⠛⠛⠛⠛⠋⠀
+"#,
+];
+
+pub const LINUX_MINT_ASCII: [&str; 2] = [
+    "35",
+    r#"[38;5;35m ___________
+[38;5;35m|_          \
+[38;5;35m  | [38;5;189m| _____[38;5;35m |
+[38;5;35m  | [38;5;189m| | | |[38;5;35m |
+[38;5;35m  | [38;5;189m| | | |[38;5;35m |
+[38;5;35m  | [38;5;189m\_____/[38;5;35m |
+[38;5;35m  \_________/
+"#,
+];
@@ -21,3 +21,32 @@ pub const LUBUNTU: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;26m⠉⠙⠛⠛⠿⠿⠿⠿⠛⠛⠋⠉⠀⠀⠀  ⠀⠀⠀⠀⠀   
 "#,
 ];
+
+pub const LUBUNTU_SMALL: [&str; 2] = [
+    "26",
+    r#"⠀⠀⠀⠀[38;5;26m⢀⣠⣴⣶⣾⣿⣷⣶⣦⣄⡀⠀⠀⠀⠀
+[38;5;26m⠀⠀⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣦⡀⠀⠀
+[38;5;26m⠀⣰⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;26m⣿⣿⣿⣿⣿⣿⣆⠀
+[38;5;26m⢰⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿[38;5;26m⣿⣿⣿⣿⣿⣿⡆
+[38;5;26m⣾⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;26m⣿⣿⣿⣿⣿⣷
+[38;5;189m⢹⣿[38;5;26m⣿⣿[38;5;189m⣿⣿⣿⣿[38;5;26m⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;26m⣿⣿⣿⣿⡏
+[38;5;26m⠈⣿⣿[38;5;189m⣿[38;5;26m⣿[38;5;189m⣿[38;5;26m⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;26m⣿⣿⡿⠁
+[38;5;26m⠀[38;5;189m⠈[38;5;26m⢿⣿[38;5;189m⣿[38;5;26m⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿[38;5;26m⣿⠟⠁⠀
+[38;5;26m⠀⠀⠀⠈⠻⢿⣿⣿⣿⣿⣿⣿⣿⡿⠟[38;5;189m⠁⠀⠀⠀
+[38;5;189m⠀⠀⠀⠀⠀⠀[38;5;26m⠈⠉⠙⠛⠋⠉⠁⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const LUBUNTU_ASCII: [&str; 2] = [
+    "26",
+    r#"[38;5;26m        .-''''''-.
+[38;5;26m      .'          '.
+[38;5;26m     /   [38;5;189m.-.[38;5;26m        \
+[38;5;26m    |   [38;5;189m/   '-._[38;5;26m     |
+[38;5;26m    |  [38;5;189m|  .-.  '.[38;5;26m    |
+[38;5;26m    |   [38;5;189m'-'  '--'[38;5;26m    |
+[38;5;26m     \              /
+[38;5;26m      '.          .'
+[38;5;26m        '-......-'
+"#,
+];
@@ -26,3 +26,34 @@ pub const MAC_OS: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;38m⠙⠻⢿⣿⣿⣿⡿⠿⠛⠉⠁⠀⠀⠀⠀⠉⠙⠛⠿⣿⣿⣿⣿⠿⠛⠁⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const MAC_OS_SMALL: [&str; 2] = [
+    "71",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;71m⢀⣴⣾⣿⠇⠀⠀⠀⠀⠀
+[38;5;71m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣰⣿⣿⣿⠟⠀⠀⠀⠀⠀⠀
+[38;5;71m⠀⠀⠀⠀⢀⣀⣀⣀⠀⠀⣿⡿⠟⢉⣀⣀⣀⠀⠀⠀⠀
+[38;5;71m⠀⢀⣤⣾⣿⣿⣿⣿⣿⣷⣶⣶⣿⣿⣿⣿⣿⣿⣷⣄⠀
+[38;5;214m⢠[38;5;71m⣾⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡿⠋⠀
+[38;5;214m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡿⠀⠀⠀
+[38;5;208m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣇⠀⠀⠀
+[38;5;208m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;167m⡄⠀⠀
+[38;5;167m⠸⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣦⣄
+[38;5;167m⠀[38;5;96m⠹⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠃
+[38;5;96m⠀⠀⠙⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡿⠃⠀
+[38;5;96m⠀⠀⠀[38;5;38m⠈⠻⢿⣿⣿⡿⠟⠛⠛⠿⢿⣿⣿⡿⠋⠀⠀⠀
+"#,
+];
+
+pub const MAC_OS_ASCII: [&str; 2] = [
+    "71",
+    r#"[38;5;71m              .:
+[38;5;71m            .::'
+[38;5;214m     .-''-. '' .-''-.
+[38;5;208m    /               \
+[38;5;167m   |               .'
+[38;5;167m   |              :
+[38;5;96m    \              '.
+[38;5;38m     \              /
+[38;5;38m      '-._.--._.--'
+"#,
+];
@@ -26,3 +26,32 @@ pub const MAGEIA: [&str; 2] = [
     
 "#,
 ];
+
+pub const MAGEIA_SMALL: [&str; 2] = [
+    "32",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;32m⢀⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠘⢋⣤⡄⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠰⣷⠍⠁⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⣴⣶⡄⢿⡷⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀[38;5;17m⢀⣄[38;5;32m⡈⠉⠀⠀[38;5;17m⢀⣠⡀⠀⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⣰⡟⠉⠛⠛⠛⠛⠛⠉⢻⣆⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⣿⠀⠀⠀⠀⠀⠀⠀⠀⠀⣿⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⢻⡄⠀⠀⠀⠀⠀⠀⠀⢰⡟⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⠈⠻⣤⡀⠀⠀⠀⢀⣴⠟⠁⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⠀⠀⠈⠙⠛⠿⠛⠋⠁⠀⠀⠀⠀⠀⠀⠀
+[38;5;17m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const MAGEIA_ASCII: [&str; 2] = [
+    "32",
+    r#"[38;5;17m         o
+[38;5;17m        o
+[38;5;17m       O
+[38;5;17m      o
+[38;5;32m  .------------.
+[38;5;32m  \            /
+[38;5;32m   '.        .'
+[38;5;32m     '------'
+"#,
+];
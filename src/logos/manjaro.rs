@@ -23,3 +23,32 @@ pub const MANJARO: [&str; 2] = [
 [38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠀⠀⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿
 "#,
 ];
+
+pub const MANJARO_SMALL: [&str; 2] = [
+    "71",
+    r#"[38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢀[38;5;71m⣀⣀⣀⣀⣀⡀⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⣿⣿⣿⣿⣿⣿⠀[38;5;29m⢸[38;5;71m⣿⣿⣿⣿⣿⡇⠀[38;5;29m⣿[38;5;71m⣿⣿⣿⣿⣿
+[38;5;71m⠛⠛⠛⠛⠛⠛⠀[38;5;29m⠘[38;5;71m⠛⠛⠛⠛⠛⠃⠀[38;5;29m⠛[38;5;71m⠛⠛⠛⠛⠛
+"#,
+];
+
+pub const MANJARO_ASCII: [&str; 2] = [
+    "71",
+    r#"[38;5;71m ##############  ######
+[38;5;71m ##############  ######
+[38;5;71m ######          ######
+[38;5;71m ######  ######  ######
+[38;5;71m ######  ######  ######
+[38;5;71m ######  ######  ######
+[38;5;71m ######  ######  ######
+[38;5;71m ######  ######  ######
+"#,
+];
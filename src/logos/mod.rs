@@ -1,13 +1,27 @@
+use crate::config::{LogoConfig, LogoSize};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use unicode_segmentation::UnicodeSegmentation;
+
 pub mod alpine;
 pub mod arch_linux;
+pub mod ascii;
+pub mod catalog;
 pub mod cent_os;
+pub mod custom;
 pub mod debian;
 pub mod elementary_os;
 pub mod endeavour;
 pub mod fedora;
 pub mod freebsd;
 pub mod gentoo;
+#[cfg(feature = "image")]
+pub mod graphics;
 pub mod kubuntu;
+pub mod layout;
 pub mod linux;
 pub mod linux_mint;
 pub mod lubuntu;
@@ -15,6 +29,8 @@ pub mod mac_os;
 pub mod mageia;
 pub mod manjaro;
 pub mod open_suse;
+#[cfg(feature = "image")]
+pub mod picture;
 pub mod pop_os;
 pub mod raspbian;
 pub mod rhel;
@@ -26,3 +42,527 @@ pub mod windows_11;
 pub mod windows_7;
 pub mod xubuntu;
 pub mod zorin_os;
+
+/// A builtin logo: its name, the other names it can be selected with, the logo itself (its ANSI
+/// color code followed by its lines), its small variant, half as wide and high, and its variant
+/// drawn with ASCII characters only.
+pub type BuiltinLogo = (
+    &'static str,
+    &'static [&'static str],
+    [&'static str; 2],
+    [&'static str; 2],
+    [&'static str; 2],
+);
+
+pub const LOGOS: &[BuiltinLogo] = &[
+    (
+        "windows11",
+        &[],
+        windows_11::WINDOWS11,
+        windows_11::WINDOWS11_SMALL,
+        windows_11::WINDOWS11_ASCII,
+    ),
+    (
+        "windows10",
+        &[],
+        windows_10::WINDOWS10,
+        windows_10::WINDOWS10_SMALL,
+        windows_10::WINDOWS10_ASCII,
+    ),
+    (
+        "windows7",
+        &[],
+        windows_7::WINDOWS7,
+        windows_7::WINDOWS7_SMALL,
+        windows_7::WINDOWS7_ASCII,
+    ),
+    (
+        "linux",
+        &[],
+        linux::LINUX,
+        linux::LINUX_SMALL,
+        linux::LINUX_ASCII,
+    ),
+    (
+        "manjaro",
+        &["manjarolinux"],
+        manjaro::MANJARO,
+        manjaro::MANJARO_SMALL,
+        manjaro::MANJARO_ASCII,
+    ),
+    (
+        "ubuntu",
+        &[],
+        ubuntu::UBUNTU,
+        ubuntu::UBUNTU_SMALL,
+        ubuntu::UBUNTU_ASCII,
+    ),
+    (
+        "archlinux",
+        &[],
+        arch_linux::ARCH_LINUX,
+        arch_linux::ARCH_LINUX_SMALL,
+        arch_linux::ARCH_LINUX_ASCII,
+    ),
+    (
+        "gentoo",
+        &[],
+        gentoo::GENTOO,
+        gentoo::GENTOO_SMALL,
+        gentoo::GENTOO_ASCII,
+    ),
+    (
+        "fedora",
+        &["fedoralinux"],
+        fedora::FEDORA,
+        fedora::FEDORA_SMALL,
+        fedora::FEDORA_ASCII,
+    ),
+    (
+        "zorinos",
+        &[],
+        zorin_os::ZORIN_OS,
+        zorin_os::ZORIN_OS_SMALL,
+        zorin_os::ZORIN_OS_ASCII,
+    ),
+    (
+        "linuxmint",
+        &[],
+        linux_mint::LINUX_MINT,
+        linux_mint::LINUX_MINT_SMALL,
+        linux_mint::LINUX_MINT_ASCII,
+    ),
+    (
+        "macos",
+        &["apple", "osx"],
+        mac_os::MAC_OS,
+        mac_os::MAC_OS_SMALL,
+        mac_os::MAC_OS_ASCII,
+    ),
+    (
+        "opensuse",
+        &[],
+        open_suse::OPEN_SUSE,
+        open_suse::OPEN_SUSE_SMALL,
+        open_suse::OPEN_SUSE_ASCII,
+    ),
+    (
+        "freebsd",
+        &[],
+        freebsd::FREEBSD,
+        freebsd::FREEBSD_SMALL,
+        freebsd::FREEBSD_ASCII,
+    ),
+    (
+        "kubuntu",
+        &[],
+        kubuntu::KUBUNTU,
+        kubuntu::KUBUNTU_SMALL,
+        kubuntu::KUBUNTU_ASCII,
+    ),
+    (
+        "lubuntu",
+        &[],
+        lubuntu::LUBUNTU,
+        lubuntu::LUBUNTU_SMALL,
+        lubuntu::LUBUNTU_ASCII,
+    ),
+    (
+        "xubuntu",
+        &[],
+        xubuntu::XUBUNTU,
+        xubuntu::XUBUNTU_SMALL,
+        xubuntu::XUBUNTU_ASCII,
+    ),
+    (
+        "raspbian",
+        &[],
+        raspbian::RASPBIAN,
+        raspbian::RASPBIAN_SMALL,
+        raspbian::RASPBIAN_ASCII,
+    ),
+    (
+        "popos",
+        &[],
+        pop_os::POP_OS,
+        pop_os::POP_OS_SMALL,
+        pop_os::POP_OS_ASCII,
+    ),
+    (
+        "endeavour",
+        &[],
+        endeavour::ENDEAVOUR,
+        endeavour::ENDEAVOUR_SMALL,
+        endeavour::ENDEAVOUR_ASCII,
+    ),
+    (
+        "centos",
+        &[],
+        cent_os::CENT_OS,
+        cent_os::CENT_OS_SMALL,
+        cent_os::CENT_OS_ASCII,
+    ),
+    ("rhel", &[], rhel::RHEL, rhel::RHEL_SMALL, rhel::RHEL_ASCII),
+    (
+        "mageia",
+        &[],
+        mageia::MAGEIA,
+        mageia::MAGEIA_SMALL,
+        mageia::MAGEIA_ASCII,
+    ),
+    (
+        "ubuntumate",
+        &[],
+        ubuntu_mate::UBUNTU_MATE,
+        ubuntu_mate::UBUNTU_MATE_SMALL,
+        ubuntu_mate::UBUNTU_MATE_ASCII,
+    ),
+    (
+        "elementaryos",
+        &[],
+        elementary_os::ELEMENTARY_OS,
+        elementary_os::ELEMENTARY_OS_SMALL,
+        elementary_os::ELEMENTARY_OS_ASCII,
+    ),
+    (
+        "solaris",
+        &[],
+        solaris::SOLARIS,
+        solaris::SOLARIS_SMALL,
+        solaris::SOLARIS_ASCII,
+    ),
+    (
+        "alpine",
+        &[],
+        alpine::ALPINE,
+        alpine::ALPINE_SMALL,
+        alpine::ALPINE_ASCII,
+    ),
+    (
+        "debian",
+        &[],
+        debian::DEBIAN,
+        debian::DEBIAN_SMALL,
+        debian::DEBIAN_ASCII,
+    ),
+];
+
+/// Normalizes a distribution or logo name the way they are matched, e.g. `Linux Mint` -> `linuxmint`.
+pub fn normalize_name(name: &str) -> String {
+    name.replace(' ', "").to_lowercase()
+}
+
+/// The number of `${c1}`..`${c6}` color placeholders of the neofetch logo format.
+pub const PLACEHOLDERS: usize = 6;
+
+/// The index of a placeholder color from its name, `c1` being 0.
+pub fn placeholder_index(name: &str) -> Option<usize> {
+    let number: usize = name.strip_prefix('c')?.parse().ok()?;
+    (1..=PLACEHOLDERS).contains(&number).then(|| number - 1)
+}
+
+/// A color of a logo, written as a code of the 256 colors palette (`32`), `#rrggbb` or
+/// `r, g, b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawLogoColor", into = "String")]
+pub enum LogoColor {
+    /// A color of the 256 colors palette.
+    Ansi(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Default for LogoColor {
+    fn default() -> Self {
+        Self::Ansi(6)
+    }
+}
+
+impl LogoColor {
+    /// The escape code printing text in this color.
+    pub fn escape(self) -> String {
+        match self {
+            Self::Ansi(code) => format!("\x1b[38;5;{}m", code),
+            Self::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        }
+    }
+}
+
+impl FromStr for LogoColor {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value: &str = value.trim();
+        let invalid = || {
+            format!(
+                "expected a color code from 0 to 255, `#rrggbb` or `r, g, b`, found `{}`",
+                value
+            )
+        };
+        if let Some(hex) = value.strip_prefix('#') {
+            let channel = |index: usize| {
+                hex.get(index..index + 2)
+                    .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            };
+            return match (hex.len(), channel(0), channel(2), channel(4)) {
+                (6, Some(r), Some(g), Some(b)) => Ok(Self::Rgb(r, g, b)),
+                _ => Err(invalid()),
+            };
+        }
+        let channels: Vec<u8> = value
+            .split(',')
+            .map(|channel| channel.trim().parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        match channels.as_slice() {
+            [code] => Ok(Self::Ansi(*code)),
+            [r, g, b] => Ok(Self::Rgb(*r, *g, *b)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for LogoColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ansi(code) => write!(f, "{}", code),
+            Self::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+        }
+    }
+}
+
+/// A color as written in the configuration, where a palette code is usually not quoted.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawLogoColor {
+    Code(u64),
+    Text(String),
+}
+
+impl TryFrom<RawLogoColor> for LogoColor {
+    type Error = String;
+
+    fn try_from(value: RawLogoColor) -> Result<Self, Self::Error> {
+        match value {
+            RawLogoColor::Code(code) => code.to_string().parse(),
+            RawLogoColor::Text(text) => text.parse(),
+        }
+    }
+}
+
+impl From<LogoColor> for String {
+    fn from(value: LogoColor) -> Self {
+        value.to_string()
+    }
+}
+
+/// A logo printed next to the information, builtin or read from a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Logo {
+    pub name: String,
+    /// The colors of the `${c1}`..`${c6}` placeholders, the first one being the accent color,
+    /// also used for the labels unless `text_color_header` is set.
+    pub colors: Vec<LogoColor>,
+    /// The lines of the logo, colored with placeholders and ANSI escape codes.
+    pub art: String,
+    /// The file the logo is read from, `None` for a builtin logo.
+    pub path: Option<PathBuf>,
+    /// The art of the small variant, `None` for a logo file.
+    pub small: Option<String>,
+    /// The art drawn with ASCII characters only, `None` for a logo file.
+    pub ascii: Option<String>,
+    /// The escape code drawing a picture over the blank art once it is printed, with a terminal
+    /// graphics protocol.
+    pub graphic: Option<String>,
+}
+
+impl Logo {
+    fn builtin(name: &str, logo: [&str; 2], small: [&str; 2], ascii: [&str; 2]) -> Self {
+        Self {
+            name: name.to_owned(),
+            colors: vec![logo[0].parse().unwrap_or_default()],
+            art: logo[1].to_owned(),
+            path: None,
+            small: Some(small[1].to_owned()),
+            ascii: Some(ascii[1].to_owned()),
+            graphic: None,
+        }
+    }
+
+    /// The same logo drawn with ASCII characters only: the ASCII variant of a builtin logo,
+    /// which has no small one, or the art of a logo file redrawn by [`ascii::to_ascii`].
+    pub fn to_ascii(mut self) -> Self {
+        match self.ascii.take() {
+            Some(ascii) => {
+                self.art = ascii;
+                self.small = None;
+            }
+            None => {
+                self.art = ascii::to_ascii(&self.art);
+                self.small = self.small.as_deref().map(ascii::to_ascii);
+            }
+        }
+        self
+    }
+
+    /// The small variant of the logo, the logo itself when it has none.
+    pub fn to_small(mut self) -> Self {
+        if let Some(small) = self.small.take() {
+            self.art = small;
+        }
+        self
+    }
+
+    /// The variant of the logo to print along with `infos` in a terminal of `terminal` columns
+    /// and rows, laid out as set in `config`. With [`LogoSize::Auto`], the large variant is kept
+    /// when it fits, then the small one, and `None` is returned when the information would not
+    /// fit along with either. The logo is kept as it is when the size of the terminal is
+    /// unknown, e.g. in a pipe.
+    pub fn fit(
+        self,
+        config: &LogoConfig,
+        terminal: Option<(usize, usize)>,
+        infos: &[String],
+    ) -> Option<Self> {
+        let (columns, rows): (usize, usize) = match (config.size, terminal) {
+            (LogoSize::Large, _) => return Some(self),
+            (LogoSize::Small, _) => return Some(self.to_small()),
+            (LogoSize::Auto, None) => return Some(self),
+            (LogoSize::Auto, Some(terminal)) => terminal,
+        };
+        // The output is surrounded by 2 empty lines, and may not fit anyway without a logo.
+        let fits = |logo: &Self| {
+            let lines: Vec<String> = layout::arrange(logo, infos, config.position, config.padding);
+            lines.iter().all(|line| display_width(line) <= columns)
+                && lines.len() <= rows.saturating_sub(2).max(infos.len())
+        };
+        if fits(&self) {
+            return Some(self);
+        }
+        self.small.is_some().then(|| self.to_small()).filter(fits)
+    }
+
+    pub fn color(&self) -> LogoColor {
+        self.colors.first().copied().unwrap_or_default()
+    }
+
+    /// The color of a placeholder, the accent color when the logo does not set it.
+    pub fn placeholder_color(&self, index: usize) -> LogoColor {
+        self.colors
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| self.color())
+    }
+
+    /// Replaces the colors of the placeholders named in `colors`, e.g. `c2`.
+    pub fn with_colors(mut self, colors: &BTreeMap<String, LogoColor>) -> Self {
+        for (name, color) in colors {
+            if let Some(index) = placeholder_index(name) {
+                if self.colors.len() <= index {
+                    let accent: LogoColor = self.color();
+                    self.colors.resize(index + 1, accent);
+                }
+                self.colors[index] = *color;
+            }
+        }
+        self
+    }
+
+    /// The lines to print, with the placeholders replaced by escape codes. Each line starts
+    /// in the color the previous one ends with, the accent color for the first one, and ends
+    /// with a reset so that the information printed next to it is not colored.
+    pub fn lines(&self) -> Vec<String> {
+        let mut active: String = self.color().escape();
+        self.art
+            .lines()
+            .map(|line| {
+                let mut output: String = active.clone();
+                let mut rest: &str = line;
+                while let Some(start) = rest.find(['$', '\x1b']) {
+                    output.push_str(&rest[..start]);
+                    rest = &rest[start..];
+                    let placeholder: Option<(usize, &str)> =
+                        rest.strip_prefix("${").and_then(|after| {
+                            let (name, tail): (&str, &str) = after.split_once('}')?;
+                            Some((placeholder_index(name)?, tail))
+                        });
+                    let escape_end: Option<usize> = rest
+                        .strip_prefix("\x1b[")
+                        .and_then(|after| after.find(|c: char| !c.is_ascii_digit() && c != ';'))
+                        .map(|end| end + 2)
+                        .filter(|end| rest[*end..].starts_with('m'));
+                    if let Some((index, tail)) = placeholder {
+                        active = self.placeholder_color(index).escape();
+                        output.push_str(&active);
+                        rest = tail;
+                    } else if let Some(end) = escape_end {
+                        active = rest[..=end].to_owned();
+                        output.push_str(&active);
+                        rest = &rest[end + 1..];
+                    } else {
+                        let character_length: usize = rest.chars().next().map_or(1, char::len_utf8);
+                        output.push_str(&rest[..character_length]);
+                        rest = &rest[character_length..];
+                    }
+                }
+                output.push_str(rest);
+                output.push_str("\x1b[0m");
+                output
+            })
+            .collect()
+    }
+
+    /// The number of columns each line takes, without the placeholders and escape codes.
+    pub fn widths(&self) -> Vec<usize> {
+        self.lines()
+            .iter()
+            .map(|line| display_width(line))
+            .collect()
+    }
+}
+
+/// The number of columns `line` takes, without its escape codes.
+pub fn display_width(line: &str) -> usize {
+    String::from_utf8_lossy(&strip_ansi_escapes::strip(line))
+        .graphemes(true)
+        .count()
+}
+
+/// The builtin logo named `name` or one of its aliases.
+pub fn builtin_logo(name: &str) -> Option<Logo> {
+    let name: String = normalize_name(name);
+    LOGOS
+        .iter()
+        .find(|(logo_name, aliases, ..)| *logo_name == name || aliases.contains(&name.as_str()))
+        .map(|(logo_name, _, logo, small, ascii)| Logo::builtin(logo_name, *logo, *small, *ascii))
+}
+
+/// The logo named `name`: a file of `dirs` named after it or after the builtin logo it is an
+/// alias of, then the builtin logo.
+pub fn get_logo_in(name: &str, dirs: &[PathBuf]) -> Result<Option<Logo>, String> {
+    let builtin: Option<Logo> = builtin_logo(name);
+    let path: Option<PathBuf> = custom::find_logo_file(name, dirs).or_else(|| {
+        builtin
+            .as_ref()
+            .and_then(|builtin| custom::find_logo_file(&builtin.name, dirs))
+    });
+    match path {
+        Some(path) => custom::read_logo_file(&path).map(Some),
+        None => Ok(builtin),
+    }
+}
+
+/// The names and aliases that can be given to `--logo`, those of the logo files of `dirs`
+/// included.
+pub fn logo_names(dirs: &[PathBuf]) -> Vec<String> {
+    let mut names: Vec<String> = LOGOS
+        .iter()
+        .flat_map(|(name, aliases, ..)| std::iter::once(*name).chain(aliases.iter().copied()))
+        .map(str::to_owned)
+        .collect();
+    for dir in dirs {
+        names.extend(custom::logo_files(dir).into_iter().map(|(name, _)| name));
+    }
+    names.sort();
+    names.dedup();
+    names
+}
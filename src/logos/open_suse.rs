@@ -12,3 +12,26 @@ pub const OPEN_SUSE: [&str; 2] = [
 [38;5;70m⠀⠀⠈⠙⠻⠿⣿⣿⡿⠿⠋⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const OPEN_SUSE_SMALL: [&str; 2] = [
+    "70",
+    r#"⠀⠀⠀[38;5;70m⢀⣠⣴⣶⣾⣿⣿⣿⣿⣿⣶⣶⣴⣶⣶⢦⢄⠀
+[38;5;70m⠀⢠⣶⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡻⢇⣳⣃⡧
+[38;5;70m⣰⡿⠛⣉⣉⠛⢿⣿⣿⣿⣿⣿⣿⣿⡿⠿⢿⣷⠾⠷⠂
+[38;5;70m⢿⡇⢺⣭⡉⣿⡄⠹⣿⠀⠀⠀⠙⢿⡇⠀⠀⠀⠀⠀⠀
+[38;5;70m⠈⠻⢶⣤⣴⠟⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const OPEN_SUSE_ASCII: [&str; 2] = [
+    "70",
+    r#"[38;5;70m        .--------.
+[38;5;70m    .--'    .-.   '.
+[38;5;70m   (  _    ( o )    \
+[38;5;70m    '' '.   '-'  .-. |
+[38;5;70m          '-.   (   )|
+[38;5;70m         .-'     '-' |
+[38;5;70m   .----'          .'
+[38;5;70m    '-------------'
+"#,
+];
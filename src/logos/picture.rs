@@ -0,0 +1,248 @@
+use crate::logos::{normalize_name, Logo, LogoColor};
+use image::imageops::FilterType;
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::path::Path;
+
+/// The characters a picture is drawn with.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PictureCharacters {
+    /// One Braille character for 2 by 4 pixels, each pixel being a dot raised or not.
+    Braille,
+    /// One `▀` for 2 pixels on top of each other, in the colors of both.
+    HalfBlocks,
+}
+
+impl PictureCharacters {
+    /// The pixels drawn by one character, as columns and rows. The pixels are about square
+    /// either way, a character being twice as high as wide.
+    fn cell_size(self) -> (u32, u32) {
+        match self {
+            Self::Braille => (2, 4),
+            Self::HalfBlocks => (1, 2),
+        }
+    }
+}
+
+/// The bit of a Braille character raising the dot in column `x` and row `y` of its cell.
+const BRAILLE_DOTS: [[u8; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// The size in pixels of a picture of `size` pixels scaled to be `width` columns wide, or less
+/// so that it is at most `height` rows high, a cell being `cell` pixels. The aspect ratio of
+/// the picture is kept.
+pub fn scale_picture(
+    size: (u32, u32),
+    cell: (u32, u32),
+    width: usize,
+    height: Option<usize>,
+) -> (u32, u32) {
+    let (picture_width, picture_height): (f64, f64) = (size.0.max(1) as f64, size.1.max(1) as f64);
+    let mut scale: f64 = (width.max(1) as u32 * cell.0) as f64 / picture_width;
+    if let Some(height) = height {
+        scale = scale.min((height.max(1) as u32 * cell.1) as f64 / picture_height);
+    }
+    (
+        ((picture_width * scale).round() as u32).max(1),
+        ((picture_height * scale).round() as u32).max(1),
+    )
+}
+
+/// Reads the picture at `path` and draws it, see [`picture_to_logo`].
+pub fn render_picture(
+    path: &Path,
+    characters: PictureCharacters,
+    width: usize,
+    height: Option<usize>,
+) -> Result<Logo, String> {
+    let picture: DynamicImage =
+        image::open(path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let mut logo: Logo = picture_to_logo(&picture, characters, width, height);
+    logo.name = path
+        .file_stem()
+        .map(|name| normalize_name(&name.to_string_lossy()))
+        .unwrap_or_default();
+    logo.path = Some(path.to_path_buf());
+    Ok(logo)
+}
+
+/// Draws `picture` with `characters` in 24-bit colors, `width` columns wide, or less so that it
+/// is at most `height` rows high, keeping its aspect ratio. The transparent pixels are left
+/// blank, and the pixels that are neither drawn nor blank are spread with Floyd-Steinberg
+/// dithering: the transparency for both, and the brightness of opaque pictures for the
+/// Braille dots. The accent color of the logo is the average color of what is drawn.
+pub fn picture_to_logo(
+    picture: &DynamicImage,
+    characters: PictureCharacters,
+    width: usize,
+    height: Option<usize>,
+) -> Logo {
+    let (cell_width, cell_height): (u32, u32) = characters.cell_size();
+    let (pixel_width, pixel_height): (u32, u32) = scale_picture(
+        (picture.width(), picture.height()),
+        (cell_width, cell_height),
+        width,
+        height,
+    );
+    let pixels: RgbaImage = resize(picture, pixel_width, pixel_height);
+
+    let transparent: bool = pixels.pixels().any(|pixel| pixel[3] < u8::MAX);
+    let drawn: Vec<bool> = dither(
+        pixels
+            .pixels()
+            .map(|pixel| match characters {
+                PictureCharacters::Braille if !transparent => luminance(pixel),
+                _ => pixel[3] as f32 / 255.0,
+            })
+            .collect(),
+        pixel_width as usize,
+    );
+    let is_drawn = |x: u32, y: u32| {
+        x < pixel_width && y < pixel_height && drawn[(y * pixel_width + x) as usize]
+    };
+    let color = |x: u32, y: u32| {
+        let [r, g, b, _]: [u8; 4] = pixels.get_pixel(x, y).0;
+        (r, g, b)
+    };
+
+    let mut art: Vec<String> = Vec::new();
+    for row in 0..pixel_height.div_ceil(cell_height) {
+        let mut line: String = String::default();
+        let mut active: Option<String> = None;
+        for column in 0..pixel_width.div_ceil(cell_width) {
+            let (x, y): (u32, u32) = (column * cell_width, row * cell_height);
+            // The escape code to print before the character, `None` when it does not matter.
+            let (character, escape): (char, Option<String>) = match characters {
+                PictureCharacters::Braille => {
+                    let mut dots: u8 = 0;
+                    let mut colors: Vec<(u8, u8, u8)> = Vec::new();
+                    for (dx, column_dots) in BRAILLE_DOTS.iter().enumerate() {
+                        for (dy, dot) in column_dots.iter().enumerate() {
+                            if is_drawn(x + dx as u32, y + dy as u32) {
+                                dots |= dot;
+                                colors.push(color(x + dx as u32, y + dy as u32));
+                            }
+                        }
+                    }
+                    let character: char = char::from_u32(0x2800 + dots as u32).unwrap_or(' ');
+                    (character, average(&colors).map(foreground))
+                }
+                PictureCharacters::HalfBlocks => {
+                    let top: Option<(u8, u8, u8)> = is_drawn(x, y).then(|| color(x, y));
+                    let bottom: Option<(u8, u8, u8)> = is_drawn(x, y + 1).then(|| color(x, y + 1));
+                    let (character, escape): (char, String) = match (top, bottom) {
+                        (Some(top), Some(bottom)) => (
+                            '▀',
+                            format!(
+                                "{}\x1b[48;2;{};{};{}m",
+                                foreground(top),
+                                bottom.0,
+                                bottom.1,
+                                bottom.2
+                            ),
+                        ),
+                        (Some(top), None) => ('▀', format!("\x1b[0m{}", foreground(top))),
+                        (None, Some(bottom)) => ('▄', format!("\x1b[0m{}", foreground(bottom))),
+                        // The background of the previous character must not fill this one.
+                        (None, None) => (' ', "\x1b[0m".to_owned()),
+                    };
+                    (character, Some(escape))
+                }
+            };
+            if let Some(escape) = escape.filter(|escape| active.as_ref() != Some(escape)) {
+                line.push_str(&escape);
+                active = Some(escape);
+            }
+            line.push(character);
+        }
+        art.push(line);
+    }
+
+    let (r, g, b): (u8, u8, u8) = accent_color(&pixels, is_drawn);
+    Logo {
+        name: "picture".to_owned(),
+        colors: vec![LogoColor::Rgb(r, g, b)],
+        art: art.join("\n"),
+        path: None,
+        small: None,
+        ascii: None,
+        graphic: None,
+    }
+}
+
+/// The pixels of `picture` resized to `width` by `height`, the picture being left as it is
+/// when it already has this size since filtering would blur it.
+pub(crate) fn resize(picture: &DynamicImage, width: u32, height: u32) -> RgbaImage {
+    if (picture.width(), picture.height()) == (width, height) {
+        picture.to_rgba8()
+    } else {
+        picture
+            .resize_exact(width, height, FilterType::Triangle)
+            .to_rgba8()
+    }
+}
+
+/// The average color of the pixels of `pixels` that are drawn, white when none is.
+pub(crate) fn accent_color(
+    pixels: &RgbaImage,
+    is_drawn: impl Fn(u32, u32) -> bool,
+) -> (u8, u8, u8) {
+    let colors: Vec<(u8, u8, u8)> = pixels
+        .enumerate_pixels()
+        .filter(|(x, y, _)| is_drawn(*x, *y))
+        .map(|(_, _, pixel)| (pixel[0], pixel[1], pixel[2]))
+        .collect();
+    average(&colors).unwrap_or((255, 255, 255))
+}
+
+fn foreground((r, g, b): (u8, u8, u8)) -> String {
+    format!("\x1b[38;2;{};{};{}m", r, g, b)
+}
+
+/// The brightness of an opaque pixel, from 0 to 1.
+fn luminance(pixel: &Rgba<u8>) -> f32 {
+    (0.2126 * pixel[0] as f32 + 0.7152 * pixel[1] as f32 + 0.0722 * pixel[2] as f32) / 255.0
+}
+
+fn average(colors: &[(u8, u8, u8)]) -> Option<(u8, u8, u8)> {
+    if colors.is_empty() {
+        return None;
+    }
+    let channel = |value: fn(&(u8, u8, u8)) -> u8| {
+        (colors
+            .iter()
+            .map(|color| value(color) as usize)
+            .sum::<usize>()
+            / colors.len()) as u8
+    };
+    Some((
+        channel(|color| color.0),
+        channel(|color| color.1),
+        channel(|color| color.2),
+    ))
+}
+
+/// Turns values from 0 to 1 into drawn pixels or not with Floyd-Steinberg dithering, the
+/// values being the rows of a picture `width` pixels wide.
+fn dither(mut values: Vec<f32>, width: usize) -> Vec<bool> {
+    let mut drawn: Vec<bool> = Vec::with_capacity(values.len());
+    for index in 0..values.len() {
+        let value: f32 = values[index];
+        let is_drawn: bool = value >= 0.5;
+        let error: f32 = value - if is_drawn { 1.0 } else { 0.0 };
+        let x: usize = index % width;
+        let mut spread = |offset: usize, weight: f32| {
+            if let Some(value) = values.get_mut(index + offset) {
+                *value += error * weight;
+            }
+        };
+        if x + 1 < width {
+            spread(1, 7.0 / 16.0);
+            spread(width + 1, 1.0 / 16.0);
+        }
+        if x > 0 {
+            spread(width - 1, 3.0 / 16.0);
+        }
+        spread(width, 5.0 / 16.0);
+        drawn.push(is_drawn);
+    }
+    drawn
+}
@@ -22,3 +22,32 @@ pub const POP_OS: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;45m⠈⠙⠛⠻⠿⢿⣿⣿⣿⣿⣿⣿⡿⠿⠟⠛⠋⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const POP_OS_SMALL: [&str; 2] = [
+    "45",
+    r#"⠀⠀⠀⠀[38;5;45m⢀⣠⣴⣶⣿⣿⣿⣿⣿⣶⣦⣄⡀⠀⠀⠀⠀
+[38;5;45m⠀⠀⢠⣶⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;45m⣿⣿⣿⣿⣿⣿⣿⣶⡄⠀⠀
+[38;5;45m⠀⣴⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿[38;5;45m⣿⣿⣿⣿⣿⣿⣿⣦⠀
+[38;5;45m⣸⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿[38;5;189m⣿⣿[38;5;45m⣿⣿⣿⣿⣇
+[38;5;45m⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿[38;5;45m⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿⣿⣿⣿
+[38;5;45m⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿⣿⣿⣿⣿
+[38;5;45m⢹⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿[38;5;45m⣿⣿⣿⣿⣿⣿⣿⣿⣿⡏
+[38;5;45m⠀⠻⣿⣿⣿⣿⣿⣿⣿[38;5;189m⣿⣿[38;5;45m⣿⣿[38;5;189m⣿[38;5;45m⣿⣿⣿⣿⣿⠟⠀
+[38;5;45m⠀⠀⠘⠿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;45m⠿⠃⠀⠀
+[38;5;45m⠀⠀⠀⠀⠈⠙⠻⠿⣿⣿⣿⣿⣿⠿⠟⠋⠁⠀⠀⠀⠀
+"#,
+];
+
+pub const POP_OS_ASCII: [&str; 2] = [
+    "45",
+    r#"[38;5;45m     .--------------.
+[38;5;45m   .'                '.
+[38;5;45m  /   [38;5;189m.---.    _[38;5;45m       \
+[38;5;45m |    [38;5;189m|   |   / /[38;5;45m       |
+[38;5;45m |    [38;5;189m|---'  / /[38;5;45m        |
+[38;5;45m |    [38;5;189m|     /_/[38;5;45m         |
+[38;5;45m  \   [38;5;189m|  _______[38;5;45m       /
+[38;5;45m   '.                .'
+[38;5;45m     '--------------'
+"#,
+];
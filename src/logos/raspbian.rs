@@ -28,3 +28,36 @@ pub const RASPBIAN: [&str; 2] = [
 [38;5;125m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const RASPBIAN_SMALL: [&str; 2] = [
+    "125",
+    r#"⠀⠀[38;5;106m⣀⣤⣤⣤⣤⣄⣀⠀⠀⠀⣀⣤⣤⣤⣤⣠⣀⠀⠀
+[38;5;106m⠀⠀⢻⣿⣿⣿⡿⣿⣿⣷⠀⣼⣿⣿⡿⣿⣿⣿⣿⠀⠀
+[38;5;106m⠀⠀⠈⢿⣿⣿⣿⣶⣝⠋⠀⠙⢋⣵⣿⣿⣿⡿⠃⠀⠀
+[38;5;106m⠀⠀⠀⠀⠙⠛⠿⠛⠁[38;5;125m⣀⣀⣀⠀[38;5;106m⠙⠻⠟⠋⠁⠀⠀⠀
+[38;5;106m⠀⠀⠀[38;5;125m⢠⣶⡿⠋⠀⢾⣿⣿⣿⣷⠈⠻⣿⣦⡀⠀⠀⠀
+[38;5;125m⠀⠀⠀⠛⠋⢀⣤⣶⣤⡈⠉⢉⣴⣶⣦⣌⠙⠧⠀⠀⠀
+[38;5;125m⠀⢀⣴⡄⢰⣿⣿⣿⣿⣿⠀⢾⣿⣿⣿⣿⣇⢰⣦⡀⠀
+[38;5;125m⠀⣿⣿⠃⠸⣿⣿⣿⡿⠋⠀⠘⢿⣿⣿⣿⠏⠸⣿⡷⠀
+[38;5;125m⠀⠘⠏⣀⡀⠈⠉⠉⣠⣾⣿⣷⣦⡈⠉⠁⣀⣄⠛⠁⠀
+[38;5;125m⠀⠀⠰⣿⣿⣷⡄⠸⣿⣿⣿⣿⣿⡇⢠⣾⣿⣿⡇⠀⠀
+[38;5;125m⠀⠀⠀⠹⣿⣿⣿⠀⠙⠻⠿⠿⠋⠀⣿⣿⣿⠟⠀⠀⠀
+[38;5;125m⠀⠀⠀⠀⠀⠉⠁⠀⣴⣶⣶⣶⣶⠄⠈⠉⠀⠀⠀⠀⠀
+[38;5;125m⠀⠀⠀⠀⠀⠀⠀⠀⠈⠛⠛⠛⠁⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const RASPBIAN_ASCII: [&str; 2] = [
+    "125",
+    r#"[38;5;106m    .~~.   .~~.
+[38;5;106m   '. \ ' ' / .'
+[38;5;125m    .~ .~~~..~.
+[38;5;125m   : .~.'~'.~. :
+[38;5;125m  ~ (   ) (   ) ~
+[38;5;125m ( : '~'.~.'~' : )
+[38;5;125m  ~ .~ (   ) ~. ~
+[38;5;125m   (  : '~' :  )
+[38;5;125m    '~ .~~~. ~'
+[38;5;125m        '~'
+"#,
+];
@@ -21,3 +21,30 @@ pub const RHEL: [&str; 2] = [
 [38;5;9m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const RHEL_SMALL: [&str; 2] = [
+    "9",
+    r#"⠀⠀⠀⠀⠀⠀[38;5;9m⣠⣤⣄⣠⣴⣦⣤⣀⠀⠀⠀⠀⠀⠀⠀
+[38;5;9m⠀⠀⠀⠀⠀⣸⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⡀⠀⠀⠀⠀
+[38;5;9m⠀⠀⠀⠀⠠⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣧⠀⠀⠀⠀
+[38;5;9m⣠⣴⣾⣿⡀⠙⠻⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡄⠀⠀⠀
+[38;5;9m⢿⣿⣿⣿⣷⣄⡀⠀⠉⠛⠻⠿⠿⣿⡿⠿⠟⢱⣤⡀⠀
+[38;5;9m⠈⠻⣿⣿⣿⣿⣿⣶⣤⣄⣀⣀⠀⠀⠀⣀⣠⣼⣿⣿⣆
+[38;5;9m⠀⠀⠈⠛⠿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡿
+[38;5;9m⠀⠀⠀⠀⠀⠀⠉⠛⠿⠿⣿⣿⣿⣿⣿⣿⣿⣿⡿⠟⠁
+[38;5;9m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠉⠉⠉⠉⠀⠀⠀⠀
+[38;5;9m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const RHEL_ASCII: [&str; 2] = [
+    "9",
+    r#"[38;5;9m            .-''''-.
+[38;5;9m           /        \
+[38;5;9m          |          |
+[38;5;9m     .----'          '----.
+[38;5;9m    (  '-.            .-'  )
+[38;5;9m     '-.  ''------''   .-'
+[38;5;9m        ''-----------''
+"#,
+];
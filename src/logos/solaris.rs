@@ -18,3 +18,28 @@ pub const SOLARIS: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀[38;5;63m⠀⠉⠉⠉⠛⠛⠛⠛⠻⠿⠶⠶⠦⠤⠤⠤  ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀      
 "#,
 ];
+
+pub const SOLARIS_SMALL: [&str; 2] = [
+    "125",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;125m⡆⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;125m⠀⠀⠀⠀⠠⡀⠀⠀⠀⣷⠀⠀⢀⡞⠀⠀⠀⠀⠀⠀⠀
+[38;5;125m⠀⠀⠀⠀⠀⠹⣦⡀⠠⡿⠆⢠⣿⠃⠀⠀⣀⠤⠀⠀⠀
+[38;5;125m⠀⠀⠤⣤⣀⣀⡘⠋⠀⠀⠀⠀⠉⢠⣶⠟⠁⠀⠀⠀⠀
+[38;5;125m⠀⠀⠀⠈⠙⠻⠁⠀⠀⠀[38;5;63m⢀⣀⣀⣀[38;5;125m⣁[38;5;63m⣀⣀⣀⡀⠀⠀
+[38;5;63m⠀⣀⣤⣴⣶⣶⡿⠿⠛⠛⠋⠉⠉⠉⠉⠉⠁⠀⠀⠀⠀
+[38;5;63m⢾⣿⣿⣿⣿⣏⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;63m⠀⠉⠛⠛⠿⠿⠷⠦⣤⣄⣀⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const SOLARIS_ASCII: [&str; 2] = [
+    "125",
+    r#"[38;5;63m              |
+[38;5;63m       \      |      /
+[38;5;63m        \     |     /
+[38;5;63m    '.   \    |    /   .'
+[38;5;125m      '.   .-''''-.   .'
+[38;5;125m   -----  /        \  -----
+[38;5;63m   .'    '-.______.-'    '.
+"#,
+];
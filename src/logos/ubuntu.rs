@@ -23,3 +23,33 @@ pub const UBUNTU: [&str; 2] = [
 [38;5;202m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⠻⢿⣿⠿⠟⠁⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const UBUNTU_SMALL: [&str; 2] = [
+    "202",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;202m⢀⣶⣿⣷⣄⠀⠀
+[38;5;202m⠀⠀⠀⠀⠀⠀⠀⠀⠀⢾⣿⣿⣿⣧⠘⢿⣿⣿⠟⠀⠀
+[38;5;202m⠀⠀⠀⠀⠀⣠⣾⣷⡀⠀⢻⣿⣿⣿⣷⣤⣭⣥⣤⡀⠀
+[38;5;202m⠀⠀⠀⠀⣼⣿⣿⣿⣿⠀⠀⠀⠀⠈⠙⠻⣿⣿⣿⣿⡄
+[38;5;202m⣠⣶⣶⣦⡈⣿⣿⣿⠃⠀⠀⠀⠀⠀⠀⠀⠹⠿⠿⠿⠷
+[38;5;202m⢿⣿⣿⣿⠇⣼⣿⣿⠀⠀⠀⠀⠀⠀⠀⠀⢀⣀⣀⣀⣀
+[38;5;202m⠀⠉⠉⠁⣴⣿⣿⣿⣧⠀⠀⠀⠀⠀⠀⢀⣾⣿⣿⣿⡏
+[38;5;202m⠀⠀⠀⠀⠘⢿⣿⣿⠟⠀⢠⣤⣤⣴⣾⣿⣿⣿⣿⠟⠀
+[38;5;202m⠀⠀⠀⠀⠀⠀⠙⠋⠀⣠⣿⣿⣿⣿⠋⣠⣶⣦⡀⠀⠀
+[38;5;202m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⠛⠛⠛⠃⠸⣿⣿⣿⡿⠀⠀
+[38;5;202m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠛⠋⠀⠀⠀
+"#,
+];
+
+pub const UBUNTU_ASCII: [&str; 2] = [
+    "202",
+    r#"[38;5;202m                .-.
+[38;5;202m          .-'''(   )
+[38;5;202m        .'   .  '-'
+[38;5;202m   .-. /   .' '.    \
+[38;5;202m  (   )   |     |   |
+[38;5;202m   '-' \   '. .'    /
+[38;5;202m        '.   '  .-.
+[38;5;202m          '-...(   )
+[38;5;202m                '-'
+"#,
+];
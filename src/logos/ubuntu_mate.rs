@@ -23,3 +23,32 @@ pub const UBUNTU_MATE: [&str; 2] = [
     ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const UBUNTU_MATE_SMALL: [&str; 2] = [
+    "107",
+    r#"⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;107m⣀⣠⣤⣤⣤⣄⣀⠀⠀⠀⠀⠀⠀⠀
+[38;5;107m⠀⠀⠀⠀⠀⠀⣠⣶⣿⣿⣿⣿⣿⣿⣿⣿⣿⣶⣄⠀⠀⠀⠀
+[38;5;107m⠀⠀⠀⠀⢠⣾⣿⣿⣿⣿⣿⣿⣿⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⣿⣷⡄⠀⠀
+[38;5;107m⠀⠀⠀⢠⣿⣿⣿⣿⣿[38;5;188m⣿⣿[38;5;107m⣿⣿⣿[38;5;188m⣿⣿⣿[38;5;107m⣿⣿⣿⣿⡄⠀
+[38;5;107m⠀⠀⠀⣾⣿⣿⣿⣿[38;5;188m⣿[38;5;107m⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⣿⣿⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⣷⠀
+[38;5;107m⠀⠀⠀⣿⣿⣿[38;5;188m⣿⣿⣿[38;5;107m⣿⣿⣿⣿⣿⣿⣿[38;5;188m⣿[38;5;107m⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⠀
+[38;5;107m⠀⠀⠀⢹⣿⣿[38;5;188m⣿[38;5;107m⣿⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⣿⣿[38;5;188m⣿[38;5;107m⣿⣿⣿⣿⣿⡏⠀
+[38;5;107m⠀⠀⠀⠀⢻⣿⣿⣿[38;5;188m⣿⣿[38;5;107m⣿⣿[38;5;188m⣿⣿⣿⣿[38;5;107m⣿⣿⣿⣿⡟⠀⠀
+[38;5;107m⠀⠀⠀⠀⠀⠙⢿⣿⣿⣿⣿[38;5;188m⣿⣿⣿⣿⣿⣿[38;5;107m⣿⡿⠋⠀⠀⠀
+[38;5;107m⠀⠀⠀⠀⠀⠀⠀⠉⠛⠿⢿⣿⣿⣿⡿⠿⠛⠉⠀⠀⠀⠀⠀
+[38;5;107m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const UBUNTU_MATE_ASCII: [&str; 2] = [
+    "107",
+    r#"[38;5;107m        .-''''''-.
+[38;5;107m      .'   [38;5;188m.-.[38;5;107m    '.
+[38;5;107m     /    [38;5;188m(   )[38;5;107m     \
+[38;5;107m    |  [38;5;188m.-. '-' .-.[38;5;107m   |
+[38;5;107m    | [38;5;188m(   )   (   )[38;5;107m  |
+[38;5;107m     \ [38;5;188m'-'     '-'[38;5;107m  /
+[38;5;107m      '.          .'
+[38;5;107m        '-......-'
+"#,
+];
@@ -22,3 +22,34 @@ pub const WINDOWS11: [&str; 2] = [
 ⠀[38;5;23m⠈⠈⠀⠁⠀⠁⠀⠀⠁⠀⠁⠁⠁⠈⠀⠀⠀⠀⠀⠀[38;5;32m⠀⠀⠀⠁⠈⠈⠈⠀⠈⠀⠁⠀⠈⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const WINDOWS11_SMALL: [&str; 2] = [
+    "81",
+    r#"[38;5;81m⢸⣿⣿⣿⣿[38;5;75m⣿⣿⣟⣫[38;5;38m⡇⠸⡚⢴⠥⡊⢌[38;5;32m⠢⢊⠈⠂
+[38;5;81m⢸⣿⣿⣿⣿[38;5;75m⣿⣿⢿[38;5;39m⢽[38;5;38m⡆⢘⢍⡒⢍⠪⠢[38;5;32m⢡⢂⠔⠅
+[38;5;75m⢸[38;5;81m⣿⣿[38;5;75m⣿⣿⣿⢟[38;5;39m⣽⠗[38;5;38m⡅[38;5;32m⢀[38;5;38m⢡⠎⡄⢑[38;5;32m⢁⢌⢂⠀⡂
+[38;5;75m⢸⡿⣿⢿⡯⡷[38;5;39m⢝[38;5;38m⣶⢫⡁[38;5;32m⢀⢢⠁⠔⠈⠀⡐⠀⠀⠀
+[38;5;38m⠘[38;5;39m⠚⠓⠋⠚[38;5;38m⠙⠑⠃⠋⠂⠀⠀[38;5;32m⠂⠈⠊⠈⠀⠊⠀⠀
+[38;5;38m⠠⢐[38;5;32m⢑⠌⡀⠕⠄⠠⠀⠀⠠⠀⠀⠀⠀⠀⠀⠀⠀⠄
+[38;5;38m⠈⢅⠢[38;5;32m⠑⠈⢀⠐⠀⠀⠀⠀⠀⠀⠀⠐⠀⠀⠀⠀⠀
+[38;5;32m⠐⡠⢃⢌⠂⠀⠀⠀⠠⠀⠀⠁⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠈⠀⠀⠁⠀⠀⠀⠀⠀⠀⠀⠀⠄⠀⠀⠀⠀⠀⠀⠀
+[38;5;32m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const WINDOWS11_ASCII: [&str; 2] = [
+    "81",
+    r#"[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+[38;5;81m  ##########  ##########
+"#,
+];
@@ -21,3 +21,31 @@ pub const WINDOWS7: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;208m⠉⠛[38;5;214m⠿[38;5;222m⠿[38;5;229m⣿⣿⣿[38;5;227m⡿[38;5;11m⠿⠿⠛[38;5;220m⠛[38;5;172m⠉[38;5;52m⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const WINDOWS7_SMALL: [&str; 2] = [
+    "81",
+    r#"⠀⠀⠀⠀[38;5;124m⢀⣤[38;5;160m⣴[38;5;9m⣶[38;5;217m⣶⣶[38;5;160m⣤[38;5;124m⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;124m⠀⠀⠀⢀[38;5;160m⣿⣿[38;5;9m⣿[38;5;203m⣿[38;5;210m⣿⣿[38;5;160m⣿[38;5;124m⣿[38;5;22m⢠⣦⣄[38;5;149m⣀⣀⣀⣀⣀⣄
+[38;5;149m⠀⠀⠀[38;5;124m⣸[38;5;160m⣿⣿[38;5;9m⣿[38;5;203m⣿[38;5;210m⣿[38;5;167m⣿[38;5;124m⣿[38;5;88m⠇[38;5;22m⣾[38;5;28m⣿[38;5;70m⣿[38;5;112m⣿[38;5;149m⣿[38;5;106m⣿[38;5;149m⣿[38;5;148m⣿[38;5;106m⠇
+[38;5;106m⠀⠀[38;5;124m⢠[38;5;88m⣿[38;5;124m⣿[38;5;160m⣿[38;5;9m⣿[38;5;203m⣿[38;5;167m⣿[38;5;124m⣿⡿[38;5;22m⢸⣿[38;5;64m⣿[38;5;112m⣿[38;5;149m⣿[38;5;191m⣿⣿[38;5;149m⣿[38;5;112m⡿⠀
+[38;5;112m⠀⠀[38;5;81m⣨⣭⣶[38;5;159m⣶⣶[38;5;117m⣶[38;5;39m⣦[38;5;25m⣍[38;5;160m⠃[38;5;22m⢿[38;5;64m⣿[38;5;70m⣿[38;5;149m⣿[38;5;191m⣿⣿⣿[38;5;148m⣿⠃⠀
+[38;5;148m⠀[38;5;31m⢰⣿[38;5;75m⣿[38;5;81m⣿[38;5;117m⣿[38;5;159m⣿[38;5;117m⣿[38;5;32m⣿[38;5;26m⡿[38;5;208m⣴[38;5;214m⣶⣽[38;5;113m⣛⣛[38;5;150m⣛[38;5;149m⣛[38;5;220m⣭[38;5;172m⡅⠀⠀
+[38;5;23m⢀[38;5;31m⣿[38;5;33m⣿[38;5;81m⣿[38;5;117m⣿[38;5;159m⣿[38;5;117m⣿[38;5;75m⣿[38;5;26m⣿[38;5;208m⢷[38;5;166m⣿[38;5;214m⣿[38;5;220m⣿[38;5;227m⣿⣿⣿⣿[38;5;220m⣿[38;5;214m⠃⠀⠀
+[38;5;24m⣸[38;5;32m⣿[38;5;38m⣿[38;5;75m⣿[38;5;117m⣿⣿[38;5;74m⣿[38;5;26m⣿⡟[38;5;208m⣾[38;5;214m⣿[38;5;220m⣿[38;5;227m⣿[38;5;228m⣿[38;5;227m⣿⣿⣿[38;5;220m⡏⠀⠀⠀
+[38;5;26m⠉[38;5;25m⠉⠀⠀⠀⠀[38;5;23m⠈[38;5;39m⠙[38;5;208m⠹⣿[38;5;214m⣿[38;5;220m⣿[38;5;228m⣿⣿[38;5;227m⣿⣿[38;5;220m⡿[38;5;94m⠁⠀⠀⠀
+[38;5;94m⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;208m⠈[38;5;214m⠙[38;5;229m⠛⠛[38;5;227m⠛[38;5;11m⠋[38;5;220m⠉⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const WINDOWS7_ASCII: [&str; 2] = [
+    "81",
+    r#"[38;5;160m        .-''-._.-.   [38;5;112m.-''-._.-.
+[38;5;160m       /        /   [38;5;112m/        /
+[38;5;160m      /        /   [38;5;112m/        /
+[38;5;160m     '-._.-''-'   [38;5;112m'-._.-''-'
+[38;5;32m     .-''-._.-.   [38;5;220m.-''-._.-.
+[38;5;32m    /        /   [38;5;220m/        /
+[38;5;32m   /        /   [38;5;220m/        /
+[38;5;32m  '-._.-''-'   [38;5;220m'-._.-''-'
+"#,
+];
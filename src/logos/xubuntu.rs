@@ -23,3 +23,33 @@ pub const XUBUNTU: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;68m⠀⠉⠛⠛⠿⠿⢿⣿⣿⣿⣿⡿⠿⠿⠛⠛⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const XUBUNTU_SMALL: [&str; 2] = [
+    "68",
+    r#"⠀⠀⠀⠀[38;5;68m⢀⣠⣴⣶⣿⣿⣿⣿⣿⣶⣦⣄⡀⠀⠀⠀⠀
+[38;5;68m⠀⠀⢀⣴⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣦⡀⠀⠀
+[38;5;68m⠀⣰⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣆⠀
+[38;5;68m⢰⣿⣿⣿⣿[38;5;189m⣿[38;5;68m⣿⣿[38;5;189m⣿⣿[38;5;68m⣿⣿⣿[38;5;189m⣿[38;5;68m⣿⣿[38;5;189m⣿[38;5;68m⣿⣿⣿⡆
+[38;5;68m⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿[38;5;68m⣿⣿⣿[38;5;189m⣿[38;5;68m⣿⣿⣿⣿⣿⣿⣿
+[38;5;68m⣿⣿⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;68m⣿⣿⣿⣿⣿
+[38;5;68m⢻⣿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;68m⣿⣿⣿⡟
+[38;5;68m⠈⢿⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿[38;5;68m⣿⣿⣿⡿⠁
+[38;5;68m⠀⠈⠻⣿⣿[38;5;189m⣿⣿⣿⣿⣿⣿⣿[38;5;68m⣿⣿⣿⣿⣿⣿⠟⠁⠀
+[38;5;68m⠀⠀⠀⠈⠻⢿⣿⣿⣿⣿⣿⣿⣿⣿⣿⡿⠟⠁⠀⠀⠀
+[38;5;68m⠀⠀⠀⠀⠀⠀⠈⠉⠛⠛⠛⠛⠛⠉⠁⠀⠀⠀⠀⠀⠀
+"#,
+];
+
+pub const XUBUNTU_ASCII: [&str; 2] = [
+    "68",
+    r#"[38;5;68m        .-''''''-.
+[38;5;68m      .'          '.
+[38;5;68m     /  [38;5;189m_   _[38;5;68m       \
+[38;5;68m    |  [38;5;189m( \_/ )[38;5;68m       |
+[38;5;68m    |   [38;5;189m\   /___[38;5;68m     |
+[38;5;68m    |   [38;5;189m/     __)[38;5;68m    |
+[38;5;68m     \ [38;5;189m(___/--'[38;5;68m     /
+[38;5;68m      '.          .'
+[38;5;68m        '-......-'
+"#,
+];
@@ -20,3 +20,29 @@ pub const ZORIN_OS: [&str; 2] = [
 ⠀⠀⠀⠀⠀⠀⠀⠀⠀[38;5;39m⠙⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀
 "#,
 ];
+
+pub const ZORIN_OS_SMALL: [&str; 2] = [
+    "39",
+    r#"⠀⠀⠀⠀[38;5;39m⣰⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣆⠀⠀⠀⠀
+[38;5;39m⠀⠀⠀⠐⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠛⠂⠀⠀⠀
+[38;5;39m⠀⠀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⣀⠀⠀⠀⠀⠀⠀⠀⠀
+[38;5;39m⢀⣾⣿⣿⣿⣿⣿⣿⣿⣿⠿⠛⠉⠀⠀⢀⣠⣴⣾⣷⡀
+[38;5;39m⢾⣿⣿⣿⣿⣿⡿⠛⠉⠀⠀⠀⣀⣤⣾⣿⣿⣿⣿⣿⡷
+[38;5;39m⠈⢿⡿⠟⠋⠁⠀⠀⣀⣤⣶⣿⣿⣿⣿⣿⣿⣿⣿⡿⠁
+[38;5;39m⠀⠀⠀⠀⠀⠀⠀⠀⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠉⠀⠀
+[38;5;39m⠀⠀⠀⠠⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⠄⠀⠀⠀
+[38;5;39m⠀⠀⠀⠀⠹⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⣿⠏⠀⠀⠀⠀
+"#,
+];
+
+pub const ZORIN_OS_ASCII: [&str; 2] = [
+    "39",
+    r#"[38;5;39m     .--------------------.
+[38;5;39m    /   ================   \
+[38;5;39m   /                        \
+[38;5;39m  |   ====================   |
+[38;5;39m   \                        /
+[38;5;39m    \   ================   /
+[38;5;39m     '--------------------'
+"#,
+];
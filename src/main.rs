@@ -1,446 +1,418 @@
-use afetch::config::Config;
-use afetch::system::getters::{
-    get_battery, get_cpu, get_desktop, get_disks, get_gpus, get_host, get_kernel, get_memory,
-    get_network, get_os, get_packages, get_public_ip, get_resolution, get_shell, get_terminal,
-    get_terminal_font, get_uptime, get_wm,
-};
-use afetch::system::infos::Infos;
-use afetch::translations::{get_language, language_code_list};
-use afetch_colored::{AnsiOrCustom, Colorize, CustomColor};
+use afetch::cache::Cache;
+use afetch::cli::{Cli, Command, HELP};
+use afetch::config::check::{with_suggestion, Diagnostic};
+use afetch::config::layers::{self, LoadedConfig, PROFILE_ENV};
+#[cfg(feature = "image")]
+use afetch::config::LogoRenderer;
+use afetch::config::{Config, Language, LogoConfig, LogoStatus};
+use afetch::entries::Registry;
+#[cfg(feature = "image")]
+use afetch::logos::graphics::{self, GraphicsProtocol};
 #[cfg(feature = "image")]
-use image::GenericImageView;
+use afetch::logos::picture::{self, PictureCharacters};
+use afetch::logos::{self, catalog, custom, layout, Logo, LogoColor};
+use afetch::render::Renderer;
+use afetch::system::environment::Environment;
+use afetch::system::infos::Infos;
+use afetch::system::report::FetchReport;
+use afetch::template::Template;
+use afetch::timings::{self, TimingKind};
+use afetch::translations::{language_code_list, Translation, FALLBACK_CODE};
+use afetch_colored::{AnsiOrCustom, CustomColor};
 use std::collections::HashMap;
 use std::fmt::Write;
+#[cfg(feature = "image")]
+use std::io::IsTerminal;
+#[cfg(feature = "image")]
+use std::path::Path;
 use std::path::PathBuf;
 use std::process::exit;
 use std::sync::Arc;
-use unicode_segmentation::UnicodeSegmentation;
-#[cfg(feature = "image")]
-use viuer::Config as ViuerConfig;
-use whoami::{fallible::hostname, username};
-
-const DEFAULT_CONFIG: &str = "language: auto # en / fr / auto \nlogo:\n  status: enable # disable / enable\n  char_type: braille # braille / picture\n  picture_path: none # `the file path: eg: ~/pictures/some.png` / none\ntext_color:\n  - 255 # r\n  - 255 # g\n  - 255 # b\n# text_color_header:\n#   - 133 # r\n#   - 218 # g\n#   - 249 # b\ndisabled_entries:\n  - battery\n  - public-ip\n  - cpu-usage\n  - network";
-
-#[tokio::main]
-async fn main() {
-    let afetch_config_parent_path: PathBuf = dirs::config_dir().unwrap_or_else(|| {
-        println!("An error occurred while retrieving the configuration files folder, please open an issue at: https://github.com/Asthowen/AFetch/issues/new so that we can solve your issue.");
-        exit(9);
-    }).join("afetch");
-    let afetch_config_path = afetch_config_parent_path.join("config.yaml");
+use std::time::Instant;
+use tokio::runtime::{Builder, Runtime};
 
-    if !afetch_config_parent_path.exists() {
-        std::fs::create_dir_all(&afetch_config_parent_path).unwrap_or_else(|e| {
-            println!(
-                "An error occurred while creating the configuration files: {}",
-                e
-            );
+fn main() {
+    let runtime: Runtime = Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap_or_else(|error| {
+            println!("An error occurred while starting the runtime: {}", error);
             exit(9);
         });
-    }
+    runtime.block_on(run());
+    // The entries that timed out may still be running, afetch exits without waiting for them.
+    runtime.shutdown_background();
+}
 
-    let yaml_to_parse: String = if afetch_config_path.exists() {
-        std::fs::read_to_string(afetch_config_path).unwrap_or_default()
-    } else {
-        if let Err(e) = std::fs::write(afetch_config_path, DEFAULT_CONFIG) {
-            println!(
-                "An error occurred while creating the configuration file: {}",
-                e
-            );
-            exit(9);
+async fn run() {
+    let started: Instant = Instant::now();
+    let cli: Cli = Cli::parse(std::env::args().skip(1)).unwrap_or_else(|error| {
+        println!(
+            "Error: {}.\nRun `afetch --help` to see the available options.",
+            error
+        );
+        exit(9);
+    });
+    if cli.help {
+        println!("{}", HELP);
+        return;
+    }
+    if cli.version {
+        println!("afetch {}", env!("CARGO_PKG_VERSION"));
+        return;
+    }
+    if cli.timings {
+        timings::enable();
+    }
+    if let Some(Command::LangMissing(code)) = &cli.command {
+        print_missing_keys(code.as_deref());
+        return;
+    }
+    let environment: Environment = Environment::from_process();
+    let logo_dirs: Vec<PathBuf> = custom::logo_dirs(&environment, cli.config.as_deref());
+    if cli.command == Some(Command::LogosList) {
+        println!("The logos `--logo` accepts, with their other names:");
+        for entry in catalog::list_logos_in(&logo_dirs) {
+            println!("  - {}", entry);
         }
-        DEFAULT_CONFIG.to_owned()
-    };
+        return;
+    }
+    if let Some(Command::LogosShow(name)) = &cli.command {
+        print_logos(name.as_deref(), &logo_dirs);
+        return;
+    }
 
-    let yaml: Config = serde_yaml::from_str(&yaml_to_parse).unwrap_or_else(|error| match serde_yaml::from_str(DEFAULT_CONFIG) {
-        Ok(config) => {
-            println!("Your configuration is malformed ({}), I therefore use the default configuration.", error);
-            config
+    let profile: Option<String> = cli
+        .profile
+        .clone()
+        .or_else(|| std::env::var(PROFILE_ENV).ok());
+    if cli.command == Some(Command::ConfigCheck) {
+        match layers::check(cli.config.as_deref(), profile.as_deref()) {
+            Ok(loaded_config) if loaded_config.files.is_empty() => {
+                println!("No configuration file found, the default configuration is used.");
+            }
+            Ok(loaded_config) => {
+                println!("The configuration is valid, it is loaded from:");
+                for file in loaded_config.files {
+                    println!("  - {}", file.display());
+                }
+            }
+            Err(diagnostics) => {
+                println!("The configuration is invalid:");
+                for diagnostic in diagnostics {
+                    println!("  - {}", diagnostic);
+                }
+                exit(9);
+            }
         }
-        Err(error1) => {
-            println!("Your configuration is malformed ({}), unfortunately I couldn't load the default configuration ({}).", error, error1);
+        return;
+    }
+    let loaded_config: Result<LoadedConfig, Vec<Diagnostic>> =
+        layers::load(cli.config.as_deref(), profile.as_deref());
+    let mut yaml: Config = loaded_config
+        .unwrap_or_else(|diagnostics| {
+            println!("Your configuration is invalid:");
+            for diagnostic in diagnostics {
+                println!("  - {}", diagnostic);
+            }
             exit(9);
+        })
+        .config;
+    let registry: Registry = Registry::from_config(&yaml);
+    let cli_diagnostics: Vec<Diagnostic> = cli.validate(&registry, &logo_dirs);
+    if !cli_diagnostics.is_empty() {
+        for diagnostic in cli_diagnostics {
+            println!("Error: {}", diagnostic);
         }
-    });
-    let text_color: CustomColor =
-        CustomColor::new(yaml.text_color[0], yaml.text_color[1], yaml.text_color[2]);
-
-    let language: HashMap<&'static str, &'static str> = if yaml.language == "auto" {
-        let locale_value_base: String = sys_locale::get_locale()
-            .unwrap_or_else(|| String::from("en-US"))
-            .replace('_', "-");
-        let locale_value: &str = locale_value_base
-            .split('-')
-            .next()
-            .unwrap_or(&locale_value_base);
-        if language_code_list().contains(&locale_value) {
-            get_language(locale_value)
-        } else {
-            get_language("en")
-        }
-    } else if language_code_list().contains(&yaml.language.as_str()) {
-        get_language(yaml.language.as_str())
+        exit(9);
+    }
+    cli.apply(&mut yaml, &registry);
+    let cache: Cache = if yaml.cache.enabled {
+        Cache::load(&environment.sysroot, &yaml, cli.refresh)
     } else {
-        get_language("en")
+        Cache::disabled()
     };
+    timings::record(TimingKind::Step, "configuration", started.elapsed(), None);
 
-    let cli_args: Vec<String> = std::env::args().collect();
-    let search_logo_arg_opt: Option<usize> =
-        cli_args.iter().position(|r| r.to_lowercase() == "--logo");
-    let custom_logo: Option<String> = search_logo_arg_opt.and_then(|search_logo_arg| {
-        cli_args
-            .get(search_logo_arg + 1)
-            .map(|logo| logo.to_lowercase())
+    let text_color: CustomColor =
+        CustomColor::new(yaml.text_color[0], yaml.text_color[1], yaml.text_color[2]);
+
+    let language: Translation = Translation::load(&yaml.language.code()).unwrap_or_else(|error| {
+        println!("Your translation is invalid: {}", error);
+        exit(9);
     });
 
     let shared_yaml: Arc<Config> = Arc::new(yaml.clone());
 
-    let infos: Infos = Infos::init(custom_logo, Arc::clone(&shared_yaml)).await;
+    let init_started: Instant = Instant::now();
+    let infos: Arc<Infos> = Arc::new(
+        Infos::init(cli.logo.clone(), Arc::clone(&shared_yaml))
+            .await
+            .with_config_path(cli.config.clone()),
+    );
+    timings::record(
+        TimingKind::Step,
+        "system information",
+        init_started.elapsed(),
+        None,
+    );
+    // The formats have already been checked along with the rest of the configuration.
+    let formats: HashMap<String, Template> = registry.parse_formats(&yaml).unwrap_or_default();
 
-    let shared_logo_color: Arc<CustomColor> = Arc::new(text_color);
-    let shared_language = Arc::new(language.clone());
-    let shared_infos: Arc<Infos> = Arc::new(infos);
-    let logo_type: i8 = if !supports_unicode::on(supports_unicode::Stream::Stdout) {
-        3
-    } else if yaml.logo.status == "enable"
-        && cfg!(feature = "image")
-        && yaml.logo.char_type != "braille"
-    {
-        1
-    } else if yaml.logo.status == "enable" {
-        0
-    } else {
-        2
-    };
-    let logo: Option<[&str; 2]> = if logo_type == 0 {
-        shared_infos.get_os_logo()
+    let entries_started: Instant = Instant::now();
+    let report: FetchReport = registry
+        .collect(Arc::clone(&infos), Arc::clone(&shared_yaml), &cache)
+        .await;
+    cache.save();
+    timings::record(TimingKind::Step, "entries", entries_started.elapsed(), None);
+
+    if cli.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{}", json),
+            Err(error) => {
+                println!(
+                    "An error occurred while serializing the JSON output: {}",
+                    error
+                );
+                exit(9);
+            }
+        }
+        print_timings(started);
+        return;
+    }
+
+    // The Braille logos and the images cannot be drawn without Unicode.
+    let ascii_only: bool = logos::ascii::is_ascii_terminal();
+    let logo: Option<Logo> = if yaml.logo.status == LogoStatus::Enable {
+        picture_logo(&yaml.logo, ascii_only)
+            .or_else(|| {
+                infos.get_os_logo().unwrap_or_else(|error| {
+                    println!("Your logo is invalid: {}", error);
+                    exit(9);
+                })
+            })
+            .map(|logo| {
+                let logo: Logo = logo.with_colors(&yaml.logo.colors);
+                if ascii_only {
+                    logo.to_ascii()
+                } else {
+                    logo
+                }
+            })
     } else {
         None
     };
-    let header_color: AnsiOrCustom = if let Some(text_color_header) = yaml.text_color_header {
+    let header_color: AnsiOrCustom = if let Some(text_color_header) = &yaml.text_color_header {
         AnsiOrCustom::Custom(CustomColor::new(
             text_color_header[0],
             text_color_header[1],
             text_color_header[2],
         ))
-    } else if let Some(logo) = logo {
-        AnsiOrCustom::Ansi(logo[0].parse::<u8>().unwrap())
+    } else if let Some(logo) = &logo {
+        match logo.color() {
+            LogoColor::Ansi(code) => AnsiOrCustom::Ansi(code),
+            LogoColor::Rgb(r, g, b) => AnsiOrCustom::Custom(CustomColor::new(r, g, b)),
+        }
     } else {
         AnsiOrCustom::Ansi(6)
     };
 
-    let shared_header_color = Arc::new(header_color);
-
-    let (username, host): (String, String) = (username(), hostname().unwrap_or_default());
-    let mut infos_to_print: Vec<String> = Vec::new();
-    let mut output: String = String::default();
-    infos_to_print.push(format!(
-        "{}{}{}",
-        username
-            .custom_color_or_ansi_color_code(header_color)
-            .bold(),
-        "@".custom_color(text_color),
-        host.custom_color_or_ansi_color_code(header_color).bold()
-    ));
-    infos_to_print.push(format!(
-        "{}",
-        "─"
-            .repeat(username.len() + host.len() + 1)
-            .custom_color(text_color)
-    ));
-
-    let (
-        os_result,
-        host_result,
-        kernel_result,
-        uptime_result,
-        packages_result,
-        shell_result,
-        resolution_result,
-        desktop_result,
-        wm_result,
-        terminal_result,
-        terminal_font_result,
-        cpu_result,
-        gpu_results,
-        memory_result,
-        network_result,
-        disks_result,
-        public_ip_result,
-        battery_result,
-    ) = tokio::join!(
-        get_os(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        ),
-        get_host(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_kernel(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        ),
-        get_uptime(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        ),
-        get_packages(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_shell(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_resolution(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_desktop(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_wm(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_terminal(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_terminal_font(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_cpu(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_gpus(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_memory(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_network(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        ),
-        get_disks(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        ),
-        get_public_ip(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-            Arc::clone(&shared_infos)
-        ),
-        get_battery(
-            Arc::clone(&shared_yaml),
-            Arc::clone(&shared_header_color),
-            Arc::clone(&shared_logo_color),
-            Arc::clone(&shared_language),
-        )
+    let rendering_started: Instant = Instant::now();
+    let infos_to_print: Vec<String> = Renderer::new(header_color, text_color, &language)
+        .with_formats(formats)
+        .with_ascii_only(ascii_only)
+        .render(&report, &registry.layout(&yaml));
+    let terminal: Option<(usize, usize)> = terminal_size::terminal_size()
+        .map(|(width, height)| (usize::from(width.0), usize::from(height.0)));
+    let logo: Option<Logo> = logo.and_then(|logo| logo.fit(&yaml.logo, terminal, &infos_to_print));
+    print_fetch(infos_to_print, logo.as_ref(), &yaml.logo);
+    timings::record(
+        TimingKind::Step,
+        "rendering",
+        rendering_started.elapsed(),
+        None,
     );
+    print_timings(started);
+}
 
-    if let Some(os) = os_result {
-        infos_to_print.push(os);
-    }
-    if let Some(host) = host_result {
-        infos_to_print.push(host);
-    }
-    if let Some(kernel) = kernel_result {
-        infos_to_print.push(kernel);
-    }
-    if let Some(uptime) = uptime_result {
-        infos_to_print.push(uptime);
-    }
-    if let Some(packages) = packages_result {
-        infos_to_print.push(packages);
-    }
-    if let Some(shell) = shell_result {
-        infos_to_print.push(shell);
-    }
-    if let Some(resolution) = resolution_result {
-        infos_to_print.push(resolution);
-    }
-    if let Some(desktop) = desktop_result {
-        infos_to_print.push(desktop);
-    }
-    if let Some(wm) = wm_result {
-        infos_to_print.push(wm);
-    }
-    if let Some(terminal) = terminal_result {
-        infos_to_print.push(terminal);
-    }
-    if let Some(terminal_font) = terminal_font_result {
-        infos_to_print.push(terminal_font);
-    }
-    if let Some(cpu) = cpu_result {
-        infos_to_print.push(cpu);
-    }
-    if let Some(mut gpus) = gpu_results {
-        infos_to_print.append(&mut gpus);
-    }
-    if let Some(memory) = memory_result {
-        infos_to_print.push(memory);
-    }
-    if let Some(network) = network_result {
-        infos_to_print.push(network);
-    }
-    if let Some(mut disks) = disks_result {
-        infos_to_print.append(&mut disks);
-    }
-    if let Some(public_ip) = public_ip_result {
-        infos_to_print.push(public_ip);
-    }
-    if let Some(battery) = battery_result {
-        infos_to_print.push(battery);
+/// The image at `picture_path`, when `char_type` asks for it and the terminal can print it:
+/// drawn with a graphics protocol for `picture` when the terminal supports one, else converted
+/// to text.
+#[cfg(feature = "image")]
+fn picture_logo(logo_config: &LogoConfig, ascii_only: bool) -> Option<Logo> {
+    if ascii_only || !logo_config.char_type.is_picture() {
+        return None;
     }
-
-    if !yaml.disabled_entries.contains(&"color-blocks".to_owned()) {
-        let first_colors: String = (0..8).fold(String::default(), |mut acc, i| {
-            write!(&mut acc, "\x1b[4{}m   \x1b[0m", i).unwrap_or_else(|error| {
-                println!("Failed to write to string for color blocks: {}.", error);
-                exit(9);
-            });
-            acc
-        });
-
-        let second_colors: String = (0..8).fold(String::default(), |mut acc, i| {
-            write!(&mut acc, "\x1b[10{}m   \x1b[0m", i).unwrap_or_else(|error| {
-                println!("Failed to write to string for color blocks: {}.", error);
-                exit(9);
-            });
-            acc
-        });
-        infos_to_print.extend(vec![
-            String::default(),
-            String::default(),
-            first_colors,
-            second_colors,
-        ]);
+    let path: &Path = Path::new(&logo_config.picture_path);
+    let (width, height): (usize, Option<usize>) =
+        (logo_config.picture_width, logo_config.picture_height);
+    // The graphics protocols cannot be used in a pipe.
+    let protocol: Option<GraphicsProtocol> = if std::io::stdout().is_terminal() {
+        GraphicsProtocol::select(logo_config.picture_protocol, |name| {
+            std::env::var(name).ok()
+        })
+    } else {
+        None
+    };
+    let logo: Result<Logo, String> = match (logo_config.char_type, protocol) {
+        (LogoRenderer::PictureBraille, _) => {
+            picture::render_picture(path, PictureCharacters::Braille, width, height)
+        }
+        (LogoRenderer::Picture, Some(protocol)) => {
+            graphics::graphics_logo(path, protocol, graphics::cell_size(), width, height)
+        }
+        _ => picture::render_picture(path, PictureCharacters::HalfBlocks, width, height),
+    };
+    match logo {
+        Ok(logo) => Some(logo),
+        Err(error) => {
+            println!("An error occurred while loading the image: {}", error);
+            exit(9);
+        }
     }
+}
 
-    let logo_lines_option: Option<Vec<&str>> =
-        logo.map(|logo| logo[1].lines().collect::<Vec<&str>>());
+#[cfg(not(feature = "image"))]
+fn picture_logo(_logo_config: &LogoConfig, _ascii_only: bool) -> Option<Logo> {
+    None
+}
 
-    if let Some(logo_lines) = logo_lines_option {
-        let logo_escape_u8: Vec<u8> = strip_ansi_escapes::strip(logo.unwrap_or_default()[1]);
-        let logo_escape = String::from_utf8_lossy(&logo_escape_u8);
-        let logo_escape_lines: Vec<&str> = logo_escape.lines().collect::<Vec<&str>>();
-        let mut max_line_length: usize = 0;
+/// Prints the logo named `name` and its small variant, or every logo below its name when `name`
+/// is `None`, the files of `logo_dirs` included. The logos are drawn with ASCII characters when the terminal cannot print Unicode.
+fn print_logos(name: Option<&str>, logo_dirs: &[PathBuf]) {
+    let ascii_only: bool = logos::ascii::is_ascii_terminal();
+    let print_preview = |logo: Logo| {
+        let logo: Logo = if ascii_only { logo.to_ascii() } else { logo };
+        for line in catalog::preview(&logo) {
+            println!("{}", line);
+        }
+    };
 
-        for line in logo_escape_lines {
-            let line_length: usize = line.graphemes(true).count() + 6;
-            if line_length > max_line_length {
-                max_line_length = line_length;
+    let Some(name) = name else {
+        let mut failed: bool = false;
+        for (index, entry) in catalog::list_logos_in(logo_dirs).iter().enumerate() {
+            if index > 0 {
+                println!();
             }
+            println!("{}", entry);
+            match logos::get_logo_in(&entry.name, logo_dirs) {
+                Ok(logo) => logo.into_iter().for_each(print_preview),
+                Err(error) => {
+                    println!("Error: {}", error);
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            exit(9);
+        }
+        return;
+    };
+    match logos::get_logo_in(name, logo_dirs) {
+        Ok(Some(logo)) => print_preview(logo),
+        Ok(None) => {
+            println!(
+                "Error: {}",
+                with_suggestion(
+                    format!("there is no logo named `{}`", name),
+                    &logos::normalize_name(name),
+                    logos::logo_names(logo_dirs).iter().map(String::as_str),
+                )
+            );
+            exit(9);
+        }
+        Err(error) => {
+            println!("Error: {}", error);
+            exit(9);
         }
+    }
+}
 
-        let mut last_index: usize = 0;
-        for (i, info) in infos_to_print.into_iter().enumerate() {
-            if logo_lines.len() > i {
-                writeln!(output, "   {}{}   {}", logo_lines[i], "".white(), info).ok();
-            } else {
-                writeln!(output, "{}{}", " ".repeat(max_line_length), info).ok();
+/// Lists the keys missing from the translation of `code`, or from every translation but the
+/// English one.
+fn print_missing_keys(code: Option<&str>) {
+    let codes: Vec<String> = match code {
+        Some(code) => match code.parse::<Language>() {
+            Ok(language) => vec![language.code()],
+            Err(error) => {
+                println!("Error: {}", error);
+                exit(9);
             }
-            last_index += 1;
-        }
+        },
+        None => language_code_list()
+            .into_iter()
+            .filter(|code| code != FALLBACK_CODE)
+            .collect(),
+    };
 
-        if last_index < logo_lines.len() {
-            for logo_line in &logo_lines[last_index..] {
-                writeln!(output, "   {}{}   ", logo_line, "".white()).ok();
+    for code in codes {
+        let translation: Translation = Translation::load(&code).unwrap_or_else(|error| {
+            println!("Error: {}", error);
+            exit(9);
+        });
+        let missing_keys: Vec<String> = translation.missing_keys();
+        if missing_keys.is_empty() {
+            println!("The `{}` translation is complete.", code);
+        } else {
+            println!(
+                "The `{}` translation is missing {} keys, their English text is used instead:",
+                code,
+                missing_keys.len()
+            );
+            for key in missing_keys {
+                println!("  - {}", key);
             }
         }
+        let unknown_keys: Vec<&str> = translation.unknown_keys();
+        if !unknown_keys.is_empty() {
+            println!(
+                "The `{}` translation defines keys that are never used: {}",
+                code,
+                unknown_keys.join(", ")
+            );
+        }
+    }
+}
 
-        println!("\n{}", output);
-        return;
+fn print_timings(started: Instant) {
+    if timings::is_enabled() {
+        eprintln!("{}", timings::render(&timings::take(), started.elapsed()));
     }
+}
 
-    #[cfg(feature = "image")]
-    if logo_type == 1 {
-        println!();
+fn print_fetch(infos_to_print: Vec<String>, logo: Option<&Logo>, logo_config: &LogoConfig) {
+    let mut output: String = String::default();
+
+    let Some(logo) = logo else {
         for info in &infos_to_print {
-            writeln!(output, "{}{}", " ".repeat(47), info).ok();
+            writeln!(output, " {}", info).ok();
         }
-        print!("{}\x1b[{}A", output, infos_to_print.len());
-
-        let image = match image::open(&yaml.logo.picture_path) {
-            Ok(image) => image,
-            Err(e) => {
-                println!("An error occurred while loading the image: {}", e);
-                exit(9);
-            }
-        };
-        let dimensions: (u32, u32) = image.dimensions();
-        let width_ratio: f64 = dimensions.0 as f64 / 44.0;
-        let height_ratio: f64 = dimensions.1 as f64 / 44.0;
-        let ratio: f64 = width_ratio.max(height_ratio);
-        let new_width: u32 = (dimensions.0 as f64 / ratio) as u32;
-
-        let viuer_config: ViuerConfig = ViuerConfig {
-            x: 0,
-            width: Some(new_width),
-            absolute_offset: false,
-            ..ViuerConfig::default()
-        };
-        viuer::print_from_file(yaml.logo.picture_path, &viuer_config).ok();
-
-        println!();
+        println!("\n{}", output);
         return;
-    }
+    };
 
-    for info in &infos_to_print {
-        writeln!(output, " {}", info).ok();
+    let lines: Vec<String> = layout::arrange(
+        logo,
+        &infos_to_print,
+        logo_config.position,
+        logo_config.padding,
+    );
+    for line in &lines {
+        writeln!(output, "{}", line).ok();
     }
     println!("\n{}", output);
+
+    // The picture is drawn over the blank logo once everything is printed, so that the
+    // terminal has already scrolled, then the cursor is put back below the output.
+    if let Some(graphic) = &logo.graphic {
+        let (line, column): (usize, usize) =
+            layout::logo_origin(&infos_to_print, logo_config.position, logo_config.padding);
+        print!(
+            "\x1b7\x1b[{}A\x1b[{}G{}\x1b8",
+            lines.len() + 1 - line,
+            column + 1,
+            graphic
+        );
+        std::io::Write::flush(&mut std::io::stdout()).ok();
+    }
 }
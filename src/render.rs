@@ -0,0 +1,323 @@
+use crate::config::LayoutItem;
+use crate::system::report::{EntryValue, FetchReport, ReportEntry};
+use crate::template::Template;
+use crate::translations::Translation;
+use crate::utils::{convert_to_readable_unity, div_mod, format_time};
+use afetch_colored::{AnsiOrCustom, Colorize, CustomColor};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+fn format_frequency(frequency: u64, language: &Translation) -> String {
+    if frequency >= 1000 {
+        format!(
+            "{} {}",
+            language.decimal(frequency as f64 / 1000.0, 2),
+            language.get("unit-gigahertz")
+        )
+    } else {
+        format!("{} {}", frequency, language.get("unit-megahertz"))
+    }
+}
+
+fn usage_values(used: u64, total: u64, language: &Translation) -> HashMap<&'static str, String> {
+    let used_readable: String = convert_to_readable_unity(used as f64, language);
+    let total_readable: String = convert_to_readable_unity(total as f64, language);
+    let percent: f64 = if total == 0 {
+        0.0
+    } else {
+        used as f64 / total as f64 * 100.0
+    };
+
+    HashMap::from([
+        ("value", format!("{}/{}", used_readable, total_readable)),
+        ("used", used_readable),
+        ("total", total_readable),
+        ("percent", format!("{:.0}", percent)),
+    ])
+}
+
+/// Turns a [`FetchReport`] into the colored, translated lines printed next to the logo.
+pub struct Renderer<'a> {
+    pub header_color: AnsiOrCustom,
+    pub text_color: CustomColor,
+    pub language: &'a Translation,
+    /// Format templates by entry id, see [`crate::entries::Registry::parse_formats`].
+    pub formats: HashMap<String, Template>,
+    /// Whether the separator and the arrows are drawn with ASCII characters, see
+    /// [`crate::logos::ascii::is_ascii_terminal`].
+    pub ascii_only: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(
+        header_color: AnsiOrCustom,
+        text_color: CustomColor,
+        language: &'a Translation,
+    ) -> Self {
+        Self {
+            header_color,
+            text_color,
+            language,
+            formats: HashMap::new(),
+            ascii_only: false,
+        }
+    }
+
+    pub fn with_formats(mut self, formats: HashMap<String, Template>) -> Self {
+        self.formats = formats;
+        self
+    }
+
+    pub fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    pub fn render_title(&self, report: &FetchReport) -> String {
+        format!(
+            "{}{}{}",
+            report
+                .username
+                .custom_color_or_ansi_color_code(self.header_color)
+                .bold(),
+            "@".custom_color(self.text_color),
+            report
+                .hostname
+                .custom_color_or_ansi_color_code(self.header_color)
+                .bold()
+        )
+    }
+
+    pub fn render_separator(&self, report: &FetchReport) -> String {
+        let line: &str = if self.ascii_only { "-" } else { "─" };
+        format!(
+            "{}",
+            line.repeat(report.username.len() + report.hostname.len() + 1)
+                .custom_color(self.text_color)
+        )
+    }
+
+    pub fn render_section(&self, section: &str) -> String {
+        format!(
+            "{}",
+            section
+                .bold()
+                .custom_color_or_ansi_color_code(self.header_color)
+        )
+    }
+
+    pub fn render_color_blocks(&self) -> Vec<String> {
+        let first_colors: String = (0..8).fold(String::default(), |mut acc, i| {
+            write!(&mut acc, "\x1b[4{}m   \x1b[0m", i).ok();
+            acc
+        });
+        let second_colors: String = (0..8).fold(String::default(), |mut acc, i| {
+            write!(&mut acc, "\x1b[10{}m   \x1b[0m", i).ok();
+            acc
+        });
+
+        vec![first_colors, second_colors]
+    }
+
+    fn label(&self, label_key: &str) -> String {
+        self.language.get(label_key).to_owned()
+    }
+
+    fn line(&self, label_key: &str, value: &str) -> String {
+        format!(
+            "{}{}",
+            self.label(label_key)
+                .bold()
+                .custom_color_or_ansi_color_code(self.header_color),
+            value.custom_color(self.text_color)
+        )
+    }
+
+    /// The placeholder values of each line of an entry, `value` being the default layout.
+    fn entry_values(&self, value: &EntryValue) -> Vec<HashMap<&'static str, String>> {
+        match value {
+            EntryValue::Text(text) => vec![HashMap::from([("value", text.clone())])],
+            EntryValue::Duration(duration) => {
+                let formatted: String = format_time(*duration, self.language);
+                if formatted.is_empty() {
+                    return Vec::new();
+                }
+                let (minutes, seconds): (u64, u64) = div_mod(*duration, 60);
+                let (hours, minutes): (u64, u64) = div_mod(minutes, 60);
+                let (days, hours): (u64, u64) = div_mod(hours, 24);
+                vec![HashMap::from([
+                    ("value", formatted),
+                    ("days", days.to_string()),
+                    ("hours", hours.to_string()),
+                    ("minutes", minutes.to_string()),
+                    ("seconds", seconds.to_string()),
+                ])]
+            }
+            EntryValue::Packages(packages) => vec![HashMap::from([
+                (
+                    "value",
+                    packages
+                        .iter()
+                        .map(|package_manager| {
+                            format!("{} ({})", package_manager.count, package_manager.name)
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                ),
+                (
+                    "total",
+                    packages
+                        .iter()
+                        .map(|package_manager| package_manager.count)
+                        .sum::<usize>()
+                        .to_string(),
+                ),
+            ])],
+            EntryValue::Desktop(desktop) => vec![HashMap::from([
+                (
+                    "value",
+                    match &desktop.version {
+                        Some(version) => format!("{} {}", desktop.name, version),
+                        None => desktop.name.clone(),
+                    },
+                ),
+                ("name", desktop.name.clone()),
+                ("version", desktop.version.clone().unwrap_or_default()),
+            ])],
+            EntryValue::Cpu(cpu) => vec![HashMap::from([
+                (
+                    "value",
+                    match cpu.usage {
+                        Some(usage) => {
+                            format!(
+                                "{} - {}%",
+                                cpu.brand,
+                                self.language.decimal(usage.into(), 1)
+                            )
+                        }
+                        None => cpu.brand.clone(),
+                    },
+                ),
+                ("brand", cpu.brand.clone()),
+                (
+                    "usage",
+                    cpu.usage
+                        .map(|usage| self.language.decimal(usage.into(), 1))
+                        .unwrap_or_default(),
+                ),
+                ("freq", format_frequency(cpu.frequency, self.language)),
+                (
+                    "cores",
+                    cpu.cores.map(|cores| cores.to_string()).unwrap_or_default(),
+                ),
+                ("threads", cpu.threads.to_string()),
+            ])],
+            EntryValue::Gpus(gpus) => gpus
+                .iter()
+                .map(|gpu| HashMap::from([("value", gpu.clone())]))
+                .collect(),
+            EntryValue::Memory(memory) => {
+                vec![usage_values(memory.used, memory.total, self.language)]
+            }
+            EntryValue::Network(network) => {
+                let sent: String = convert_to_readable_unity(network.sent as f64, self.language);
+                let received: String =
+                    convert_to_readable_unity(network.received as f64, self.language);
+                let per_second: &str = self.language.get("unit-per-second");
+                let (sent_arrow, received_arrow): (&str, &str) = if self.ascii_only {
+                    ("v", "^")
+                } else {
+                    ("↘", "↗")
+                };
+                vec![HashMap::from([
+                    (
+                        "value",
+                        format!(
+                            "{}{} {}  {}{} {}",
+                            sent, per_second, sent_arrow, received, per_second, received_arrow
+                        ),
+                    ),
+                    ("sent", sent),
+                    ("received", received),
+                ])]
+            }
+            EntryValue::Disks(disks) => disks
+                .iter()
+                .map(|disk| {
+                    let mut values: HashMap<&'static str, String> =
+                        usage_values(disk.used, disk.total, self.language);
+                    values.insert("mount_point", disk.mount_point.clone());
+                    values
+                })
+                .collect(),
+            EntryValue::DiskUsage(disk_usage) => {
+                vec![usage_values(
+                    disk_usage.used,
+                    disk_usage.total,
+                    self.language,
+                )]
+            }
+            EntryValue::Battery(battery) => {
+                let percentage: String = format!("{:.4}", battery.percentage.to_string())
+                    .replace('.', self.language.get("decimal-separator"));
+                vec![HashMap::from([
+                    ("value", format!("{}%", percentage)),
+                    ("percentage", percentage),
+                ])]
+            }
+            EntryValue::TimedOut => vec![HashMap::from([("value", self.label("timed-out"))])],
+        }
+    }
+
+    pub fn render_entry(&self, entry: &ReportEntry) -> Vec<String> {
+        if entry.value == EntryValue::TimedOut {
+            return vec![self.line(&entry.label_key, &self.label("timed-out"))];
+        }
+        let template: Option<&Template> = self.formats.get(&entry.id);
+        self.entry_values(&entry.value)
+            .into_iter()
+            .map(|mut values| {
+                let value: String = match template {
+                    Some(template) => template.render(&values),
+                    None => values.remove("value").unwrap_or_default(),
+                };
+
+                if let EntryValue::Disks(_) = entry.value {
+                    format!(
+                        "{}{}{}{}",
+                        self.label(&entry.label_key)
+                            .bold()
+                            .custom_color_or_ansi_color_code(self.header_color),
+                        format!("({})", values["mount_point"])
+                            .custom_color_or_ansi_color_code(self.header_color),
+                        self.label("label-disk-1")
+                            .custom_color_or_ansi_color_code(self.header_color),
+                        value.custom_color(self.text_color)
+                    )
+                } else {
+                    self.line(&entry.label_key, &value)
+                }
+            })
+            .collect()
+    }
+
+    pub fn render(&self, report: &FetchReport, layout: &[LayoutItem]) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::new();
+        for item in layout {
+            match item {
+                LayoutItem::Title => lines.push(self.render_title(report)),
+                LayoutItem::Separator => lines.push(self.render_separator(report)),
+                LayoutItem::Spacer => lines.push(String::default()),
+                LayoutItem::ColorBlocks => lines.extend(self.render_color_blocks()),
+                LayoutItem::Section(section) => lines.push(self.render_section(section)),
+                LayoutItem::Entry(id) => {
+                    if let Some(entry) = report.entries.iter().find(|entry| entry.id == id.as_str())
+                    {
+                        lines.extend(self.render_entry(entry));
+                    }
+                }
+            }
+        }
+        lines
+    }
+}
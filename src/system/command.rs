@@ -0,0 +1,78 @@
+use crate::system::environment::Environment;
+use crate::utils::{command_line, return_str_from_command};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Runs the external programs the system information is gathered from.
+pub trait CommandRunner: Send + Sync {
+    /// The standard output of the command, empty if it cannot be run.
+    fn output(&self, command: &mut Command) -> String;
+
+    /// Whether `program` can be run, looked up in the `PATH` of `environment` by default.
+    fn exists(&self, program: &str, environment: &Environment) -> bool {
+        environment.command_exist(program)
+    }
+}
+
+/// Spawns real processes, killing them at the command deadline.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn output(&self, command: &mut Command) -> String {
+        return_str_from_command(command)
+    }
+}
+
+/// Serves recorded outputs instead of running anything, commands without a recorded output
+/// behaving as missing programs.
+///
+/// Outputs are looked up by command line, see [`command_line`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayRunner {
+    outputs: HashMap<String, String>,
+}
+
+impl ReplayRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_output(mut self, command_line: &str, output: &str) -> Self {
+        self.outputs
+            .insert(command_line.to_owned(), output.to_owned());
+        self
+    }
+
+    /// Reads the outputs from a YAML mapping of command lines to their output:
+    ///
+    /// ```yaml
+    /// pacman -Qq --color never: |
+    ///   base
+    ///   linux
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path: &Path = path.as_ref();
+        let content: String = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        let outputs: HashMap<String, String> = serde_yaml::from_str(&content)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        Ok(Self { outputs })
+    }
+}
+
+impl CommandRunner for ReplayRunner {
+    fn output(&self, command: &mut Command) -> String {
+        self.outputs
+            .get(&command_line(command))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn exists(&self, program: &str, _: &Environment) -> bool {
+        self.outputs
+            .keys()
+            .any(|command_line| command_line.split(' ').next() == Some(program))
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The environment variable pointing afetch at another filesystem root, e.g. a mounted system
+/// or a fixture directory.
+pub const SYSROOT_ENV: &str = "AFETCH_SYSROOT";
+
+/// The filesystem root and the environment variables the system information is read from,
+/// those of the running system unless they are injected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    pub sysroot: PathBuf,
+    pub vars: HashMap<String, String>,
+    /// The process whose parents are looked up in `/proc`, afetch itself by default.
+    pub pid: u32,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::from_process()
+    }
+}
+
+impl Environment {
+    pub fn new(sysroot: impl Into<PathBuf>, vars: HashMap<String, String>) -> Self {
+        Self {
+            sysroot: sysroot.into(),
+            vars,
+            pid: std::process::id(),
+        }
+    }
+
+    pub fn with_pid(mut self, pid: u32) -> Self {
+        self.pid = pid;
+        self
+    }
+
+    /// The variables of the afetch process, the root being `AFETCH_SYSROOT` when it is set.
+    pub fn from_process() -> Self {
+        let vars: HashMap<String, String> = std::env::vars_os()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+            .collect();
+        let sysroot: PathBuf = vars
+            .get(SYSROOT_ENV)
+            .filter(|sysroot| !sysroot.is_empty())
+            .map_or_else(|| PathBuf::from("/"), PathBuf::from);
+        Self::new(sysroot, vars)
+    }
+
+    /// Where an absolute path of the system is found, relative paths being left untouched.
+    pub fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path: &Path = path.as_ref();
+        match path.strip_prefix("/") {
+            Ok(relative_path) => self.sysroot.join(relative_path),
+            Err(_) => path.to_path_buf(),
+        }
+    }
+
+    /// Same as [`Environment::path`], a path starting with `~/` being in the home folder.
+    pub fn expand_path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path: &Path = path.as_ref();
+        match path.strip_prefix("~") {
+            Ok(relative_path) => self.path(self.home_dir().join(relative_path)),
+            Err(_) => self.path(path),
+        }
+    }
+
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.path(path).exists()
+    }
+
+    pub fn get_file_content(&self, path: impl AsRef<Path>) -> String {
+        std::fs::read_to_string(self.path(path)).unwrap_or_default()
+    }
+
+    pub fn get_file_content_without_lines(&self, path: impl AsRef<Path>) -> String {
+        self.get_file_content(path).replace('\n', "")
+    }
+
+    pub fn env_exist(&self, name: &str) -> bool {
+        self.vars.contains_key(name)
+    }
+
+    pub fn get_env(&self, name: &str) -> String {
+        self.vars.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Whether a program can be found in the `PATH` of this environment.
+    pub fn command_exist(&self, program: &str) -> bool {
+        let cwd: PathBuf = std::env::current_dir().unwrap_or_else(|_| self.sysroot.clone());
+        which::which_in(program, self.vars.get("PATH"), cwd).is_ok()
+    }
+
+    pub fn home_dir(&self) -> PathBuf {
+        self.vars
+            .get("HOME")
+            .map(PathBuf::from)
+            .or_else(dirs::home_dir)
+            .unwrap_or_default()
+    }
+
+    pub fn config_dir(&self) -> PathBuf {
+        #[cfg(target_os = "linux")]
+        {
+            self.xdg_dir("XDG_CONFIG_HOME", ".config")
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            dirs::config_dir().unwrap_or_default()
+        }
+    }
+
+    pub fn data_local_dir(&self) -> PathBuf {
+        #[cfg(target_os = "linux")]
+        {
+            self.xdg_dir("XDG_DATA_HOME", ".local/share")
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            dirs::data_local_dir().unwrap_or_default()
+        }
+    }
+
+    /// A base directory of the XDG specification, ignored when it is not absolute.
+    #[cfg(target_os = "linux")]
+    fn xdg_dir(&self, name: &str, default: &str) -> PathBuf {
+        self.vars
+            .get(name)
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .unwrap_or_else(|| self.home_dir().join(default))
+    }
+}
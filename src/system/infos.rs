@@ -1,22 +1,32 @@
-use crate::config::Config;
-use crate::logos;
-use crate::system::pid::get_ppid;
-use crate::utils::{
-    command_exist, env_exist, get_env, get_file_content, get_file_content_without_lines,
-    return_str_from_command,
+use crate::config::{Config, EntryId};
+use crate::logos::{self, custom, Logo};
+use crate::system::command::{CommandRunner, SystemRunner};
+use crate::system::environment::Environment;
+use crate::system::pid::{get_parent_pid_names, get_ppid};
+use crate::system::report::{
+    BatteryInfo, CpuInfo, DiskInfo, MemoryInfo, NetworkInfo, PackageManagerInfo,
 };
+use crate::utils::{command_deadline, remaining_time, with_command_deadline};
 use std::fs::File;
 use std::io::BufReader;
 use std::io::{BufRead, Read};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Arc;
-use sysinfo::System;
+use std::time::Instant;
+use sysinfo::{Cpu, Disks, Networks, System};
 use tokio::task;
 
+/// The time in seconds after which the public IP request is given up.
+const PUBLIC_IP_TIMEOUT: u64 = 5;
+
 pub struct Infos {
     pub sysinfo_obj: System,
+    pub environment: Environment,
+    pub runner: Arc<dyn CommandRunner>,
     pub custom_logo: Option<String>,
+    /// The `--config` file, next to which logo files are looked for.
+    pub config_path: Option<PathBuf>,
     pub home_dir: PathBuf,
     pub config_dir: PathBuf,
     pub local_dir: PathBuf,
@@ -25,30 +35,62 @@ pub struct Infos {
 impl Infos {
     pub async fn init(custom_logo: Option<String>, config: Arc<Config>) -> Self {
         let mut sysinfo_obj = System::new();
-        if !config.disabled_entries.contains(&"memory".to_owned()) {
+        if config.is_entry_enabled("memory") {
             sysinfo_obj.refresh_memory();
         }
-        if !config.disabled_entries.contains(&"cpu".to_owned()) {
+        if config.is_entry_enabled("cpu") {
             sysinfo_obj.refresh_cpu();
 
-            if !config.disabled_entries.contains(&"cpu-usage".to_owned()) {
+            if !config.is_disabled(&EntryId::CpuUsage) {
                 tokio::time::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL).await;
                 sysinfo_obj.refresh_cpu_usage();
             }
         }
 
+        let environment: Environment = Environment::from_process();
         Self {
             sysinfo_obj,
             custom_logo,
-            home_dir: dirs::home_dir().unwrap(),
-            config_dir: dirs::config_dir().unwrap(),
-            local_dir: dirs::data_local_dir().unwrap(),
+            config_path: None,
+            home_dir: environment.home_dir(),
+            config_dir: environment.config_dir(),
+            local_dir: environment.data_local_dir(),
+            environment,
+            runner: Arc::new(SystemRunner),
         }
     }
 
+    /// Reads the system from another filesystem root and environment variables, see
+    /// [`Environment`].
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.home_dir = environment.home_dir();
+        self.config_dir = environment.config_dir();
+        self.local_dir = environment.data_local_dir();
+        self.environment = environment;
+        self
+    }
+
+    /// Runs the external programs through another runner, e.g. a [`ReplayRunner`] serving
+    /// recorded outputs.
+    ///
+    /// [`ReplayRunner`]: crate::system::command::ReplayRunner
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    pub fn with_config_path(mut self, config_path: Option<PathBuf>) -> Self {
+        self.config_path = config_path;
+        self
+    }
+
+    fn command_exist(&self, program: &str) -> bool {
+        self.runner.exists(program, &self.environment)
+    }
+
     fn parse_os_release(&self, file_path: &str) -> String {
-        let contents: String = std::fs::read_to_string(file_path).unwrap_or_default();
-        contents
+        self.environment
+            .get_file_content(file_path)
             .lines()
             .find_map(|line| {
                 if let Some(("ID", part)) | Some(("NAME", part)) = line.split_once('=') {
@@ -62,49 +104,52 @@ impl Infos {
 
     #[cfg(target_family = "unix")]
     pub fn get_linux_distribution(&self) -> String {
-        let mut distribution_name: String = if Path::new("/etc/os-release").exists() {
+        let mut distribution_name: String = if self.environment.exists("/etc/os-release") {
             self.parse_os_release("/etc/os-release")
-        } else if Path::new("/usr/lib/os-release").exists() {
+        } else if self.environment.exists("/usr/lib/os-release") {
             self.parse_os_release("/usr/lib/os-release")
-        } else if Path::new("/etc/openwrt_release").exists() {
+        } else if self.environment.exists("/etc/openwrt_release") {
             self.parse_os_release("/etc/openwrt_release")
-        } else if Path::new("/etc/lsb-release").exists() {
+        } else if self.environment.exists("/etc/lsb-release") {
             self.parse_os_release("/etc/lsb-release")
-        } else if Path::new("/besdrock/etc/bedrock-release").exists()
-            && env_exist("BEDROCK_RESTRICT")
+        } else if self.environment.exists("/besdrock/etc/bedrock-release")
+            && self.environment.env_exist("BEDROCK_RESTRICT")
         {
             "Bedrock Linux".to_owned()
-        } else if Path::new("/etc/redstar-release").exists() {
+        } else if self.environment.exists("/etc/redstar-release") {
             "Red Star OS".to_owned()
-        } else if Path::new("/etc/armbian-release").exists() {
+        } else if self.environment.exists("/etc/armbian-release") {
             "Armbian".to_owned()
-        } else if Path::new("/etc/siduction-version").exists() {
+        } else if self.environment.exists("/etc/siduction-version") {
             "Siduction".to_owned()
-        } else if Path::new("/etc/mcst_version").exists() {
+        } else if self.environment.exists("/etc/mcst_version") {
             "OS Elbrus".to_owned()
-        } else if command_exist("pveversion") {
+        } else if self.command_exist("pveversion") {
             "Proxmox VE".to_owned()
-        } else if command_exist("lsb_release") {
-            match get_env("DISTRO_SHORTHAND").as_str() {
-                "on" | "off" => return_str_from_command(Command::new("lsb_release").arg("-si")),
-                _ => return_str_from_command(Command::new("lsb_release").arg("-sd")),
+        } else if self.command_exist("lsb_release") {
+            match self.environment.get_env("DISTRO_SHORTHAND").as_str() {
+                "on" | "off" => self.runner.output(Command::new("lsb_release").arg("-si")),
+                _ => self.runner.output(Command::new("lsb_release").arg("-sd")),
             }
-        } else if Path::new("/etc/GoboLinuxVersion").exists() {
+        } else if self.environment.exists("/etc/GoboLinuxVersion") {
             "GoboLinux".to_owned()
-        } else if Path::new("/etc/SDE-VERSION").exists() {
-            get_file_content_without_lines("/etc/SDE-VERSION")
-        } else if command_exist("tazpkg") {
+        } else if self.environment.exists("/etc/SDE-VERSION") {
+            self.environment
+                .get_file_content_without_lines("/etc/SDE-VERSION")
+        } else if self.command_exist("tazpkg") {
             "SliTaz".to_owned()
-        } else if command_exist("kpt") && command_exist("kpm") {
+        } else if self.command_exist("kpt") && self.command_exist("kpm") {
             "KSLinux".to_owned()
-        } else if Path::new("/system/app/").exists() && Path::new("/system/priv-app").exists() {
+        } else if self.environment.exists("/system/app/")
+            && self.environment.exists("/system/priv-app")
+        {
             "Android".to_owned()
         } else {
             String::default()
         };
 
-        if distribution_name == "Ubuntu" && env_exist("XDG_CONFIG_DIRS") {
-            let env_value: String = get_env("XDG_CONFIG_DIRS");
+        if distribution_name == "Ubuntu" && self.environment.env_exist("XDG_CONFIG_DIRS") {
+            let env_value: String = self.environment.get_env("XDG_CONFIG_DIRS");
             if env_value.contains("cinnamon") {
                 distribution_name = "Ubuntu Cinnamon".to_owned();
             } else if env_value.contains("studio") {
@@ -123,7 +168,8 @@ impl Infos {
         distribution_name
     }
 
-    pub fn get_os_logo(&self) -> Option<[&str; 2]> {
+    /// The logo of `--logo` or of the system, an error meaning that its logo file is invalid.
+    pub fn get_os_logo(&self) -> Result<Option<Logo>, String> {
         let os: String = if let Some(logo) = &self.custom_logo {
             logo.to_owned()
         } else {
@@ -174,71 +220,118 @@ impl Infos {
         .replace(' ', "")
         .to_lowercase();
 
-        match os.as_str() {
-            "windows11" => Some(logos::windows_11::WINDOWS11),
-            "windows10" => Some(logos::windows_10::WINDOWS10),
-            "windows7" => Some(logos::windows_7::WINDOWS7),
-            "linux" => Some(logos::linux::LINUX),
-            "manjaro" | "manjarolinux" => Some(logos::manjaro::MANJARO),
-            "ubuntu" => Some(logos::ubuntu::UBUNTU),
-            "archlinux" => Some(logos::arch_linux::ARCH_LINUX),
-            "gentoo" => Some(logos::gentoo::GENTOO),
-            "fedora" | "fedoralinux" => Some(logos::fedora::FEDORA),
-            "zorinos" => Some(logos::zorin_os::ZORIN_OS),
-            "linuxmint" => Some(logos::linux_mint::LINUX_MINT),
-            "macos" | "apple" | "osx" => Some(logos::mac_os::MAC_OS),
-            "opensuse" => Some(logos::open_suse::OPEN_SUSE),
-            "freebsd" => Some(logos::freebsd::FREEBSD),
-            "kubuntu" => Some(logos::kubuntu::KUBUNTU),
-            "lubuntu" => Some(logos::lubuntu::LUBUNTU),
-            "xubuntu" => Some(logos::xubuntu::XUBUNTU),
-            "raspbian" => Some(logos::raspbian::RASPBIAN),
-            "popos" => Some(logos::pop_os::POP_OS),
-            "endeavour" => Some(logos::endeavour::ENDEAVOUR),
-            "centos" => Some(logos::cent_os::CENT_OS),
-            "rhel" => Some(logos::rhel::RHEL),
-            "mageia" => Some(logos::mageia::MAGEIA),
-            "ubuntumate" => Some(logos::ubuntu_mate::UBUNTU_MATE),
-            "elementaryos" => Some(logos::elementary_os::ELEMENTARY_OS),
-            "solaris" => Some(logos::solaris::SOLARIS),
-            "alpine" => Some(logos::alpine::ALPINE),
-            "debian" => Some(logos::debian::DEBIAN),
-            _ => None,
+        logos::get_logo_in(
+            &os,
+            &custom::logo_dirs(&self.environment, self.config_path.as_deref()),
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn get_os_name(&self) -> String {
+        // The `NAME` of os-release, as read by `System::name` on the running system.
+        let (content, key): (String, &str) = match ["/etc/os-release", "/usr/lib/os-release"]
+            .into_iter()
+            .find(|path| self.environment.exists(path))
+        {
+            Some(path) => (self.environment.get_file_content(path), "NAME"),
+            None => (
+                self.environment.get_file_content("/etc/lsb-release"),
+                "DISTRIB_ID",
+            ),
+        };
+        content
+            .lines()
+            .find_map(|line| match line.split_once('=') {
+                Some((name, value)) if name == key => Some(value.trim_matches('"').to_owned()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn get_os_name(&self) -> String {
+        let system_name: String = System::name().unwrap_or_default().trim().to_owned();
+        if system_name.to_lowercase().contains("windows") {
+            format!(
+                "{} {}",
+                system_name,
+                System::os_version()
+                    .unwrap_or_default()
+                    .split(' ')
+                    .collect::<Vec<&str>>()[0]
+            )
+        } else {
+            system_name
         }
     }
 
+    pub fn get_kernel(&self) -> String {
+        System::kernel_version()
+            .unwrap_or_default()
+            .trim()
+            .replace('\n', "")
+    }
+
+    pub fn get_uptime(&self) -> u64 {
+        System::uptime()
+    }
+
     pub fn get_host(&self) -> String {
         let mut host = String::default();
         #[cfg(target_os = "linux")]
         {
-            if Path::new("/system/app/").exists() && Path::new("/system/priv-app").exists() {
+            if self.environment.exists("/system/app/")
+                && self.environment.exists("/system/priv-app")
+            {
                 host = format!(
                     "{}{}",
-                    return_str_from_command(Command::new("getprop").arg("ro.product.brand")),
-                    return_str_from_command(Command::new("getprop").arg("ro.product.model"))
+                    self.runner
+                        .output(Command::new("getprop").arg("ro.product.brand")),
+                    self.runner
+                        .output(Command::new("getprop").arg("ro.product.model"))
                 );
-            } else if Path::new("/sys/devices/virtual/dmi/id/product_name").exists()
-                && Path::new("/sys/devices/virtual/dmi/id/product_version").exists()
+            } else if self
+                .environment
+                .exists("/sys/devices/virtual/dmi/id/product_name")
+                && self
+                    .environment
+                    .exists("/sys/devices/virtual/dmi/id/product_version")
             {
                 host = format!(
                     "{} {}",
-                    get_file_content_without_lines("/sys/devices/virtual/dmi/id/product_name"),
-                    get_file_content_without_lines("/sys/devices/virtual/dmi/id/product_version")
+                    self.environment
+                        .get_file_content_without_lines("/sys/devices/virtual/dmi/id/product_name"),
+                    self.environment.get_file_content_without_lines(
+                        "/sys/devices/virtual/dmi/id/product_version"
+                    )
                 );
-            } else if Path::new("/sys/firmware/devicetree/base/model").exists() {
-                host = get_file_content_without_lines("/sys/firmware/devicetree/base/model");
-            } else if Path::new("/tmp/sysinfo/model").exists() {
-                host = get_file_content_without_lines("/tmp/sysinfo/model");
+            } else if self
+                .environment
+                .exists("/sys/firmware/devicetree/base/model")
+            {
+                host = self
+                    .environment
+                    .get_file_content_without_lines("/sys/firmware/devicetree/base/model");
+            } else if self.environment.exists("/tmp/sysinfo/model") {
+                host = self
+                    .environment
+                    .get_file_content_without_lines("/tmp/sysinfo/model");
             }
 
             if (host.contains("System Product Name") || host.is_empty())
-                && Path::new("/sys/devices/virtual/dmi/id/board_vendor").exists()
-                && Path::new("/sys/devices/virtual/dmi/id/board_name").exists()
+                && self
+                    .environment
+                    .exists("/sys/devices/virtual/dmi/id/board_vendor")
+                && self
+                    .environment
+                    .exists("/sys/devices/virtual/dmi/id/board_name")
             {
                 host = format!(
                     "{} {}",
-                    get_file_content_without_lines("/sys/devices/virtual/dmi/id/board_vendor"),
-                    get_file_content_without_lines("/sys/devices/virtual/dmi/id/board_name")
+                    self.environment
+                        .get_file_content_without_lines("/sys/devices/virtual/dmi/id/board_vendor"),
+                    self.environment
+                        .get_file_content_without_lines("/sys/devices/virtual/dmi/id/board_name")
                         .as_str(),
                 )
             }
@@ -248,16 +341,18 @@ impl Infos {
 
         #[cfg(target_os = "windows")]
         {
-            host = return_str_from_command(
-                Command::new("wmic")
-                    .arg("computersystem")
-                    .arg("get")
-                    .arg("manufacturer,model"),
-            )
-            .replace("Manufacturer  Model", "")
-            .replace("     ", " ")
-            .trim()
-            .to_owned();
+            host = self
+                .runner
+                .output(
+                    Command::new("wmic")
+                        .arg("computersystem")
+                        .arg("get")
+                        .arg("manufacturer,model"),
+                )
+                .replace("Manufacturer  Model", "")
+                .replace("     ", " ")
+                .trim()
+                .to_owned();
             host
         }
 
@@ -272,44 +367,51 @@ impl Infos {
         let mut shell_path: String = String::default();
         let mut shell_name: String = String::default();
 
-        if env_exist("SHELL") {
-            shell_path = get_env("SHELL");
+        if self.environment.env_exist("SHELL") {
+            shell_path = self.environment.get_env("SHELL");
             let shell_name_spliced: Vec<&str> = shell_path.split('/').collect::<Vec<&str>>();
             shell_name = shell_name_spliced[shell_name_spliced.len() - 1].to_owned();
         }
 
         if !shell_name.is_empty() {
-            return if env_exist("SHELL_VERSION") {
-                format!("{} {}", shell_name, get_env("SHELL_VERSION")).replace('\n', "")
+            return if self.environment.env_exist("SHELL_VERSION") {
+                format!(
+                    "{} {}",
+                    shell_name,
+                    self.environment.get_env("SHELL_VERSION")
+                )
+                .replace('\n', "")
             } else {
                 let mut shell_version: String = String::default();
                 if shell_name == "fish" {
-                    shell_version =
-                        return_str_from_command(Command::new(shell_path).arg("--version"))
-                            .split("fish, version ")
-                            .collect::<Vec<&str>>()[1]
-                            .replace('\n', "");
+                    shell_version = self
+                        .runner
+                        .output(Command::new(shell_path).arg("--version"))
+                        .split_once("fish, version ")
+                        .map(|(_, version)| version.replace('\n', ""))
+                        .unwrap_or_default();
                 } else if shell_name == "bash" {
-                    shell_version = if env_exist("BASH_VERSION") {
-                        get_env("BASH_VERSION")
+                    shell_version = if self.environment.env_exist("BASH_VERSION") {
+                        self.environment.get_env("BASH_VERSION")
                     } else {
-                        return_str_from_command(
-                            Command::new(shell_path).arg("-c").arg("echo $BASH_VERSION"),
-                        )
+                        self.runner
+                            .output(Command::new(shell_path).arg("-c").arg("echo $BASH_VERSION"))
                     };
                 } else if shell_name == "sh" {
-                    shell_version = return_str_from_command(Command::new("sh").arg("--version"))
-                        .split("GNU bash, version ")
-                        .collect::<Vec<&str>>()[1]
-                        .split(' ')
-                        .collect::<Vec<&str>>()[0]
+                    shell_version = self
+                        .runner
+                        .output(Command::new("sh").arg("--version"))
+                        .split_once("GNU bash, version ")
+                        .and_then(|(_, version)| version.split(' ').next())
+                        .unwrap_or_default()
                         .to_owned();
                 } else if shell_name == "ksh" {
-                    shell_version = return_str_from_command(Command::new("ksh").arg("--version"))
-                        .split("(AT&T Research) ")
-                        .collect::<Vec<&str>>()[1]
-                        .trim()
-                        .to_owned();
+                    shell_version = self
+                        .runner
+                        .output(Command::new("ksh").arg("--version"))
+                        .split_once("(AT&T Research) ")
+                        .map(|(_, version)| version.trim().to_owned())
+                        .unwrap_or_default();
                 }
 
                 if shell_version.is_empty() {
@@ -325,12 +427,16 @@ impl Infos {
         #[cfg(target_os = "linux")]
         {
             let mut resolution: String = String::default();
-            if command_exist("xrandr") && env_exist("DISPLAY") && !env_exist("WAYLAND_DISPLAY") {
+            if self.command_exist("xrandr")
+                && self.environment.env_exist("DISPLAY")
+                && !self.environment.env_exist("WAYLAND_DISPLAY")
+            {
                 let mut last_line: bool = false;
                 let mut temp_resolution: Vec<String> = Vec::new();
-                for line in
-                    return_str_from_command(Command::new("xrandr").arg("--nograb").arg("--current"))
-                        .lines()
+                for line in self
+                    .runner
+                    .output(Command::new("xrandr").arg("--nograb").arg("--current"))
+                    .lines()
                 {
                     if last_line {
                         temp_resolution
@@ -341,36 +447,35 @@ impl Infos {
                     }
                 }
                 resolution = temp_resolution.join(" ");
-            } else if command_exist("xwininfo")
-                && env_exist("DISPLAY")
-                && !env_exist("WAYLAND_DISPLAY")
+            } else if self.command_exist("xwininfo")
+                && self.environment.env_exist("DISPLAY")
+                && !self.environment.env_exist("WAYLAND_DISPLAY")
             {
-                let command: String =
-                    return_str_from_command(Command::new("xwininfo").arg("-root"));
-                resolution = format!(
-                    "{}x{}",
-                    command.split("Width: ").collect::<Vec<&str>>()[1]
-                        .lines()
-                        .collect::<Vec<&str>>()[0],
-                    command.split("Height: ").collect::<Vec<&str>>()[1]
-                        .lines()
-                        .collect::<Vec<&str>>()[0]
-                );
-            } else if command_exist("xdpyinfo")
-                && env_exist("DISPLAY")
-                && !env_exist("WAYLAND_DISPLAY")
+                let command: String = self.runner.output(Command::new("xwininfo").arg("-root"));
+                let dimension = |name: &str| -> Option<String> {
+                    let (_, value) = command.split_once(name)?;
+                    value.lines().next().map(str::to_owned)
+                };
+                if let (Some(width), Some(height)) = (dimension("Width: "), dimension("Height: ")) {
+                    resolution = format!("{}x{}", width, height);
+                }
+            } else if self.command_exist("xdpyinfo")
+                && self.environment.env_exist("DISPLAY")
+                && !self.environment.env_exist("WAYLAND_DISPLAY")
             {
-                resolution = return_str_from_command(&mut Command::new("xdpyinfo"))
-                    .split("dimensions: ")
-                    .collect::<Vec<&str>>()[1]
-                    .trim()
-                    .split(' ')
-                    .collect::<Vec<&str>>()[0]
+                resolution = self
+                    .runner
+                    .output(&mut Command::new("xdpyinfo"))
+                    .split_once("dimensions: ")
+                    .and_then(|(_, dimensions)| dimensions.split_whitespace().next())
+                    .unwrap_or_default()
                     .to_owned();
-            } else if Path::new("/sys/class/drm").exists() {
+            } else if self.environment.exists("/sys/class/drm") {
                 let mut temp_resolution: Vec<String> = Vec::new();
 
-                let read_dir = if let Ok(read_dir) = std::fs::read_dir("/sys/class/drm/") {
+                let read_dir = if let Ok(read_dir) =
+                    std::fs::read_dir(self.environment.path("/sys/class/drm"))
+                {
                     read_dir
                 } else {
                     return String::default();
@@ -411,26 +516,30 @@ impl Infos {
 
         #[cfg(target_os = "windows")]
         {
-            let width: String = return_str_from_command(
-                Command::new("wmic")
-                    .arg("path")
-                    .arg("Win32_VideoController")
-                    .arg("get")
-                    .arg("CurrentHorizontalResolution"),
-            )
-            .replace("CurrentHorizontalResolution", "")
-            .trim()
-            .to_owned();
-            let height: String = return_str_from_command(
-                Command::new("wmic")
-                    .arg("path")
-                    .arg("Win32_VideoController")
-                    .arg("get")
-                    .arg("CurrentVerticalResolution"),
-            )
-            .replace("CurrentVerticalResolution", "")
-            .trim()
-            .to_owned();
+            let width: String = self
+                .runner
+                .output(
+                    Command::new("wmic")
+                        .arg("path")
+                        .arg("Win32_VideoController")
+                        .arg("get")
+                        .arg("CurrentHorizontalResolution"),
+                )
+                .replace("CurrentHorizontalResolution", "")
+                .trim()
+                .to_owned();
+            let height: String = self
+                .runner
+                .output(
+                    Command::new("wmic")
+                        .arg("path")
+                        .arg("Win32_VideoController")
+                        .arg("get")
+                        .arg("CurrentVerticalResolution"),
+                )
+                .replace("CurrentVerticalResolution", "")
+                .trim()
+                .to_owned();
             format!("{}x{}", width, height)
         }
 
@@ -444,8 +553,8 @@ impl Infos {
         output.lines().count()
     }
 
-    pub async fn get_packages_number(&self) -> String {
-        let mut packages_string: Vec<String> = Vec::new();
+    pub async fn get_packages(&self) -> Vec<PackageManagerInfo> {
+        let mut packages: Vec<PackageManagerInfo> = Vec::new();
 
         #[cfg(target_family = "unix")]
         {
@@ -453,7 +562,7 @@ impl Infos {
                 ("pacman", "pacman", vec!["-Qq", "--color", "never"]),
                 ("kiss", "kiss", vec!["l"]),
                 ("cpt", "cpt-list", Vec::new()),
-                ("dpkg", "dpkg-query", vec!["-f", "'.\n'", "-W"]),
+                ("dpkg", "dpkg-query", vec!["-f", ".\n", "-W"]),
                 ("xbps-query", "xbps-query", vec!["-l"]),
                 ("apk", "apk", vec!["info"]),
                 ("opkg", "opkg", vec!["list-installed"]),
@@ -480,16 +589,26 @@ impl Infos {
                 ("snap", "mine", vec!["-q"]),
             ];
 
+            // The package managers run on other threads, which keep the deadline of this one.
+            let deadline: Option<Instant> = command_deadline();
             let mut handles = Vec::new();
             for (name, command, args) in package_managers {
-                if command_exist(command) {
-                    let handle = task::spawn(async move {
-                        let packages_count: usize = Self::count_lines_in_output(
-                            return_str_from_command(Command::new(command).args(args)),
-                        );
+                if self.command_exist(command) {
+                    let runner: Arc<dyn CommandRunner> = Arc::clone(&self.runner);
+                    // The output of `snap list` starts with a header line.
+                    let header_lines: usize = usize::from(command == "snap");
+                    let handle = task::spawn_blocking(move || {
+                        let packages_count: usize =
+                            Self::count_lines_in_output(with_command_deadline(deadline, || {
+                                runner.output(Command::new(command).args(args))
+                            }))
+                            .saturating_sub(header_lines);
 
                         if packages_count != 0 {
-                            return Some(format!("{} ({})", packages_count, name));
+                            return Some(PackageManagerInfo {
+                                name: name.to_owned(),
+                                count: packages_count,
+                            });
                         }
                         None
                     });
@@ -497,112 +616,188 @@ impl Infos {
                 }
             }
 
-            let handle_rpm = task::spawn(async move {
-                if command_exist("dnf")
-                    && command_exist("sqlite3")
-                    && Path::new("/var/cache/dnf/packages.db").exists()
-                {
-                    let packages_count = Self::count_lines_in_output(return_str_from_command(
-                        Command::new("sqlite3")
-                            .arg("/var/cache/dnf/packages.db")
-                            .arg(r#""SELECT count(pkg) FROM installed""#),
-                    ));
-                    if packages_count != 0 {
-                        return Some(format!("{} (dnf)", packages_count));
-                    }
-                } else if command_exist("rpm") {
-                    let packages_count = Self::count_lines_in_output(return_str_from_command(
-                        Command::new("rpm").arg("-qa"),
-                    ));
-                    if packages_count != 0 {
-                        return Some(format!("{} (dnf)", packages_count));
+            let dnf_database: PathBuf = self.environment.path("/var/cache/dnf/packages.db");
+            let use_dnf_database: bool =
+                self.command_exist("dnf") && self.command_exist("sqlite3") && dnf_database.exists();
+            let rpm_exists: bool = self.command_exist("rpm");
+            let runner: Arc<dyn CommandRunner> = Arc::clone(&self.runner);
+            let handle_rpm = task::spawn_blocking(move || {
+                with_command_deadline(deadline, || {
+                    if use_dnf_database {
+                        let packages_count: usize = runner
+                            .output(
+                                Command::new("sqlite3")
+                                    .arg(dnf_database)
+                                    .arg("SELECT count(pkg) FROM installed"),
+                            )
+                            .trim()
+                            .parse()
+                            .unwrap_or_default();
+                        if packages_count != 0 {
+                            return Some(PackageManagerInfo {
+                                name: "dnf".to_owned(),
+                                count: packages_count,
+                            });
+                        }
+                    } else if rpm_exists {
+                        let packages_count = Self::count_lines_in_output(
+                            runner.output(Command::new("rpm").arg("-qa")),
+                        );
+                        if packages_count != 0 {
+                            return Some(PackageManagerInfo {
+                                name: "dnf".to_owned(),
+                                count: packages_count,
+                            });
+                        }
                     }
-                }
 
-                None
+                    None
+                })
             });
             handles.push(handle_rpm);
 
             for handle in handles {
                 match handle.await {
-                    Ok(Some(formatted)) => packages_string.push(formatted),
+                    Ok(Some(package_manager)) => packages.push(package_manager),
                     Err(error) => {
                         println!("Error while fetching packages number: {}", error);
                     }
                     _ => {}
                 }
             }
-
-            packages_string.join(" ")
         }
 
         #[cfg(target_os = "windows")]
         {
-            if command_exist("choco") {
-                let choco_output: String =
-                    return_str_from_command(Command::new("choco").arg("list").arg("--localonly"));
+            if self.command_exist("choco") {
+                let choco_output: String = self
+                    .runner
+                    .output(Command::new("choco").arg("list").arg("--localonly"));
                 let choco_output_split: Vec<&str> = choco_output
                     .split(" packages installed")
                     .collect::<Vec<&str>>()[0]
                     .lines()
                     .collect::<Vec<&str>>();
-                packages_string.push(format!(
-                    "{} (chocolatey)",
-                    choco_output_split[choco_output_split.len() - 1],
-                ));
+                if let Ok(packages_count) = choco_output_split[choco_output_split.len() - 1]
+                    .trim()
+                    .parse::<usize>()
+                {
+                    packages.push(PackageManagerInfo {
+                        name: "chocolatey".to_owned(),
+                        count: packages_count,
+                    });
+                }
             }
+        }
 
-            packages_string.join(" ")
+        packages
+    }
+    pub fn get_memory(&self) -> MemoryInfo {
+        MemoryInfo {
+            used: self.sysinfo_obj.used_memory(),
+            total: self.sysinfo_obj.total_memory(),
         }
+    }
 
-        #[cfg(not(any(target_os = "windows", target_family = "unix")))]
-        {
-            // TODO - add other OS
-            String::default()
+    pub fn get_cpu(&self) -> Option<CpuInfo> {
+        let cpu_infos: &Cpu = self.sysinfo_obj.cpus().first()?;
+
+        let brand: String = if !cpu_infos.brand().is_empty() {
+            cpu_infos.brand().to_owned()
+        } else if !self.sysinfo_obj.global_cpu_info().vendor_id().is_empty() {
+            cpu_infos.vendor_id().to_owned()
+        } else {
+            return None;
+        };
+
+        Some(CpuInfo {
+            brand,
+            usage: Some(cpu_infos.cpu_usage()),
+            frequency: cpu_infos.frequency(),
+            cores: self.sysinfo_obj.physical_core_count(),
+            threads: self.sysinfo_obj.cpus().len(),
+        })
+    }
+
+    pub fn get_network(&self) -> NetworkInfo {
+        let (mut sent, mut received) = (0, 0);
+        for data in Networks::new_with_refreshed_list().list().values() {
+            sent += data.transmitted();
+            received += data.received();
         }
+        NetworkInfo { sent, received }
     }
+
+    pub fn get_disks(&self) -> Vec<DiskInfo> {
+        let mut disks: Vec<DiskInfo> = Vec::new();
+        for disk in Disks::new_with_refreshed_list().list() {
+            let mount_point: String = disk.mount_point().to_string_lossy().into_owned();
+            if mount_point.contains("/etc")
+                || mount_point.contains("/boot")
+                || mount_point.contains("/snapd")
+                || mount_point.contains("/docker")
+            {
+                continue;
+            }
+
+            disks.push(DiskInfo {
+                mount_point,
+                used: disk.total_space() - disk.available_space(),
+                total: disk.total_space(),
+            });
+        }
+        disks
+    }
+
+    pub fn get_battery(&self) -> Option<BatteryInfo> {
+        let battery_infos = starship_battery::Manager::new()
+            .and_then(|manager| manager.batteries())
+            .and_then(|mut batteries_infos| batteries_infos.next().transpose())
+            .ok()??;
+
+        Some(BatteryInfo {
+            percentage: battery_infos.state_of_charge().value * 100.0,
+        })
+    }
+
     pub fn get_public_ip(&self) -> String {
-        match minreq::get("http://ipinfo.io/ip").send() {
+        let timeout: u64 = remaining_time()
+            .map_or(PUBLIC_IP_TIMEOUT, |remaining| remaining.as_secs().max(1))
+            .min(PUBLIC_IP_TIMEOUT);
+        match minreq::get("http://ipinfo.io/ip")
+            .with_timeout(timeout)
+            .send()
+        {
             Ok(response) => response.as_str().unwrap_or_default().to_owned(),
             Err(_) => String::default(),
         }
     }
 
-    fn get_qt_bindir_path() -> String {
-        let mut path = std::env::var("PATH").unwrap_or_default();
+    fn get_qt_bindir_path(&self) -> String {
+        let mut path: String = self.environment.get_env("PATH");
         path.push(':');
 
-        if let Ok(qt_bindir_path) = Command::new("qtpaths").arg("--binaries-dir").output() {
-            if let Ok(qt_bindir_output) = String::from_utf8(qt_bindir_path.stdout) {
-                path.push_str(qt_bindir_output.trim());
-                return path;
-            }
+        let qt_bindir_path: String = self
+            .runner
+            .output(Command::new("qtpaths").arg("--binaries-dir"));
+        if qt_bindir_path.is_empty() {
+            return String::default();
         }
-        String::default()
+        path.push_str(qt_bindir_path.trim());
+        path
     }
 
-    fn get_konsole_instances() -> Vec<String> {
-        if let Ok(konsole_instances_output) = Command::new("qdbus")
-            .env("PATH", Self::get_qt_bindir_path())
-            .output()
-        {
-            if let Ok(konsole_instances_output_str) =
-                String::from_utf8(konsole_instances_output.stdout)
-            {
-                return konsole_instances_output_str
-                    .lines()
-                    .filter(|line| {
-                        line.contains("org.kde.konsole") || line.contains("org.kde.yakuake")
-                    })
-                    .map(|line| line.split_whitespace().next().unwrap().to_owned())
-                    .collect();
-            }
-        }
-        Vec::new()
+    fn get_konsole_instances(&self) -> Vec<String> {
+        self.runner
+            .output(Command::new("qdbus").env("PATH", self.get_qt_bindir_path()))
+            .lines()
+            .filter(|line| line.contains("org.kde.konsole") || line.contains("org.kde.yakuake"))
+            .map(|line| line.split_whitespace().next().unwrap().to_owned())
+            .collect()
     }
     pub fn get_terminal(&self) -> String {
-        if env_exist("TERM_PROGRAM") {
-            return match get_env("TERM_PROGRAM").trim() {
+        if self.environment.env_exist("TERM_PROGRAM") {
+            return match self.environment.get_env("TERM_PROGRAM").trim() {
                 "iTerm.app" => "iTerm2".to_owned(),
                 "Terminal.app" => "Apple Terminal".to_owned(),
                 "Hyper" => "HyperTerm".to_owned(),
@@ -610,33 +805,27 @@ impl Infos {
                 value => value.to_owned(),
             };
         }
-        if env_exist("TERM") {
-            let term: String = get_env("TERM");
+        if self.environment.env_exist("TERM") {
+            let term: String = self.environment.get_env("TERM");
             if term == "tw52" || term == "tw100" {
                 return "TosWin2".to_owned();
             }
         }
-        if env_exist("SSH_CONNECTION") {
-            return get_env("SSH_TTY");
+        if self.environment.env_exist("SSH_CONNECTION") {
+            return self.environment.get_env("SSH_TTY");
         }
-        if env_exist("WT_SESSION") {
+        if self.environment.env_exist("WT_SESSION") {
             return "Windows Terminal".to_owned();
         }
-        let pids_names: Vec<String> = match crate::system::pid::get_parent_pid_names() {
-            Ok(pids) => pids,
-            Err(error) => {
-                println!("{error}");
-                return String::default();
-            }
-        };
+        let pids_names: Vec<String> = get_parent_pid_names(&self.environment);
         let mut term: String = String::default();
-        let shell: String = get_env("SHELL");
+        let shell: String = self.environment.get_env("SHELL");
         for name in pids_names {
             match name.as_str() {
                 name if shell == name => {}
                 "sh" | "screen" | "su" | "dolphin" | "nautilus" => {}
                 "login" | "Login" | "init" | "(init)" => {
-                    term = return_str_from_command(&mut Command::new("tty"));
+                    term = self.runner.output(&mut Command::new("tty"));
                 }
                 "ruby" | "1" | "tmux" | "systemd" | "sshd" | "python" | "USER" | "PID"
                 | "kdeinit" | "launchd" | "ksmserver" => break,
@@ -666,8 +855,10 @@ impl Infos {
     pub fn get_terminal_font(&self) -> String {
         let mut term_font = String::default();
 
-        let terminal_name = if env_exist("TERM") && !get_env("TERM").starts_with("xterm") {
-            get_env("TERM")
+        let terminal_name = if self.environment.env_exist("TERM")
+            && !self.environment.get_env("TERM").starts_with("xterm")
+        {
+            self.environment.get_env("TERM")
         } else {
             self.get_terminal()
         };
@@ -681,22 +872,22 @@ impl Infos {
                 let mut config_path = Path::new(&self.config_dir)
                     .join("alacritty")
                     .join("alacritty.yml");
-                if !config_path.exists() {
+                if !self.environment.exists(&config_path) {
                     config_path = Path::new(&self.home_dir).join(".alacritty.yml");
-                    if !config_path.exists() {
+                    if !self.environment.exists(&config_path) {
                         config_path = Path::new(&self.config_dir)
                             .join("alacritty")
                             .join("alacritty.toml");
-                        if !config_path.exists() {
+                        if !self.environment.exists(&config_path) {
                             config_path = Path::new(&self.home_dir).join(".alacritty.toml");
-                            if !config_path.exists() {
+                            if !self.environment.exists(&config_path) {
                                 return String::default();
                             }
                         }
                     }
                 }
 
-                if let Ok(contents) = std::fs::read_to_string(config_path) {
+                if let Ok(contents) = std::fs::read_to_string(self.environment.path(config_path)) {
                     if let Some(line) = contents
                         .lines()
                         .find(|line| line.contains("family:") || line.contains("family = "))
@@ -711,43 +902,50 @@ impl Infos {
                 }
             }
             "apple_terminal" => {
-                term_font = return_str_from_command(
+                term_font = self.runner.output(
                     Command::new("osascript")
                         .arg("-e")
                         .arg(r#"tell application "Terminal" to font name of window frontmost"#),
                 );
             }
             "iterm2" => {
-                let current_profile_name = return_str_from_command(Command::new("osascript")
+                let current_profile_name = self.runner.output(Command::new("osascript")
                         .arg("-e")
                         .arg(r#"tell application "iTerm2" to profile name of current session of current window"#)).trim().to_owned();
 
-                let font_file = Path::new(&self.home_dir)
-                    .join("Library")
-                    .join("Preferences")
-                    .join("com.googlecode.iterm2.plist");
-
-                let profiles_count = return_str_from_command(Command::new("PlistBuddy").args([
-                    "-c",
-                    "Print ':New Bookmarks:'",
-                    &font_file.display().to_string(),
-                ]))
-                .split("Guid")
-                .count()
-                    - 1;
+                let font_file: PathBuf = self.environment.path(
+                    self.home_dir
+                        .join("Library")
+                        .join("Preferences")
+                        .join("com.googlecode.iterm2.plist"),
+                );
 
-                for i in 0..profiles_count {
-                    let profile_name = return_str_from_command(Command::new("PlistBuddy").args([
+                let profiles_count = self
+                    .runner
+                    .output(Command::new("PlistBuddy").args([
                         "-c",
-                        &format!("Print ':New Bookmarks:{}:Name:'", i),
+                        "Print ':New Bookmarks:'",
                         &font_file.display().to_string(),
                     ]))
-                    .trim()
-                    .to_owned();
+                    .split("Guid")
+                    .count()
+                    - 1;
+
+                for i in 0..profiles_count {
+                    let profile_name = self
+                        .runner
+                        .output(Command::new("PlistBuddy").args([
+                            "-c",
+                            &format!("Print ':New Bookmarks:{}:Name:'", i),
+                            &font_file.display().to_string(),
+                        ]))
+                        .trim()
+                        .to_owned();
 
                     if profile_name == current_profile_name {
-                        let temp_term_font: String =
-                            return_str_from_command(Command::new("PlistBuddy").args([
+                        let temp_term_font: String = self
+                            .runner
+                            .output(Command::new("PlistBuddy").args([
                                 "-c",
                                 &format!("Print ':New Bookmarks:{}:Normal Font:'", i),
                                 &font_file.display().to_string(),
@@ -755,8 +953,9 @@ impl Infos {
                             .trim()
                             .to_owned();
 
-                        let diff_font: String =
-                            return_str_from_command(Command::new("PlistBuddy").args([
+                        let diff_font: String = self
+                            .runner
+                            .output(Command::new("PlistBuddy").args([
                                 "-c",
                                 &format!("Print ':New Bookmarks:{}:Use Non-ASCII Font:'", i),
                                 &font_file.display().to_string(),
@@ -765,8 +964,9 @@ impl Infos {
                             .to_owned();
 
                         if diff_font == "true" {
-                            let non_ascii: String =
-                                return_str_from_command(Command::new("PlistBuddy").args([
+                            let non_ascii: String = self
+                                .runner
+                                .output(Command::new("PlistBuddy").args([
                                     "-c",
                                     &format!("Print ':New Bookmarks:{}:Non Ascii Font:'", i),
                                     &font_file.display().to_string(),
@@ -789,12 +989,12 @@ impl Infos {
                     .join("deepin")
                     .join("deepin-terminal")
                     .join("config.conf");
-                if !config_file.exists() {
+                if !self.environment.exists(&config_file) {
                     return String::default();
                 }
 
                 let mut is_next = false;
-                for line in get_file_content(config_file).lines() {
+                for line in self.environment.get_file_content(config_file).lines() {
                     if line.contains("[basic.interface.font]") {
                         is_next = true;
                     } else if is_next && line.contains("value=") {
@@ -808,11 +1008,11 @@ impl Infos {
                     .join("GNUstep")
                     .join("Defaults")
                     .join("Terminal.plist");
-                if !config_file.exists() {
+                if !self.environment.exists(&config_file) {
                     return String::default();
                 }
 
-                let file_content = get_file_content_without_lines(config_file);
+                let file_content = self.environment.get_file_content_without_lines(config_file);
                 term_font = file_content
                     .lines()
                     .filter(|line| {
@@ -824,11 +1024,11 @@ impl Infos {
             }
             "hyper" => {
                 let config_file = Path::new(&self.home_dir).join(".hyper.js");
-                if !config_file.exists() {
+                if !self.environment.exists(&config_file) {
                     return String::default();
                 }
 
-                let file_content = get_file_content_without_lines(config_file);
+                let file_content = self.environment.get_file_content_without_lines(config_file);
 
                 let temp_term_font: Option<&str> = match file_content.split("fontFamily\":").nth(1)
                 {
@@ -842,37 +1042,32 @@ impl Infos {
                 };
             }
             "kitty" | "xterm-kitty" => {
-                term_font = return_str_from_command(Command::new("kitty").arg("+runpy").arg(
+                term_font = self.runner.output(Command::new("kitty").arg("+runpy").arg(
                     "from kitty.cli import *; o = create_default_opts(); \
                 print(f'{o.font_family} {o.font_size}')",
                 ));
             }
             "konsole" | "yakuake" => {
-                let child = get_ppid(&format!("{}", std::process::id())).unwrap_or_default();
+                let child = get_ppid(&self.environment, &self.environment.pid.to_string())
+                    .unwrap_or_default();
 
-                let konsole_instances = Self::get_konsole_instances();
+                let konsole_instances = self.get_konsole_instances();
 
                 let instance_infos = konsole_instances.iter().find_map(|i| {
-                    let konsole_sessions = Command::new("qdbus").arg(i).output().ok().map_or_else(
-                        Vec::default,
-                        |output| {
-                            String::from_utf8_lossy(&output.stdout)
-                                .lines()
-                                .filter(|line| line.contains("/Sessions/"))
-                                .map(ToOwned::to_owned)
-                                .collect::<Vec<String>>()
-                        },
-                    );
+                    let konsole_sessions: Vec<String> = self
+                        .runner
+                        .output(Command::new("qdbus").arg(i))
+                        .lines()
+                        .filter(|line| line.contains("/Sessions/"))
+                        .map(ToOwned::to_owned)
+                        .collect();
 
                     konsole_sessions.iter().find_map(|session| {
-                        let session_process_id = Command::new("qdbus")
-                            .arg(i)
-                            .arg(session)
-                            .arg("processId")
-                            .output()
-                            .ok()
-                            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
-                            .unwrap_or_default();
+                        let session_process_id: String = self
+                            .runner
+                            .output(Command::new("qdbus").arg(i).arg(session).arg("processId"))
+                            .trim()
+                            .to_owned();
 
                         if child == session_process_id {
                             Some((session.clone(), i.clone()))
@@ -886,38 +1081,32 @@ impl Infos {
                     Some(instance_infos) => instance_infos,
                 };
 
-                let mut profile_name = Command::new("qdbus")
-                    .arg(&instance_infos.1)
-                    .arg(&instance_infos.0)
-                    .arg("profile")
-                    .output()
-                    .ok()
-                    .map_or_else(String::default, |output| {
-                        String::from_utf8_lossy(&output.stdout).trim().to_owned()
-                    });
+                let mut profile_name: String = self
+                    .runner
+                    .output(
+                        Command::new("qdbus")
+                            .arg(&instance_infos.1)
+                            .arg(&instance_infos.0)
+                            .arg("profile"),
+                    )
+                    .trim()
+                    .to_owned();
 
                 if profile_name.is_empty() {
-                    profile_name = Command::new("qdbus")
-                        .arg(instance_infos.1)
-                        .arg(instance_infos.0)
-                        .arg("environment")
-                        .output()
-                        .ok()
-                        .map_or_else(String::default, |output| {
-                            String::from_utf8_lossy(&output.stdout)
-                                .lines()
-                                .find_map(|line| {
-                                    if line.starts_with("KONSOLE_PROFILE_NAME=") {
-                                        Some(
-                                            line.trim_start_matches("KONSOLE_PROFILE_NAME=")
-                                                .to_owned(),
-                                        )
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .unwrap_or_default()
-                        });
+                    profile_name = self
+                        .runner
+                        .output(
+                            Command::new("qdbus")
+                                .arg(instance_infos.1)
+                                .arg(instance_infos.0)
+                                .arg("environment"),
+                        )
+                        .lines()
+                        .find_map(|line| {
+                            line.strip_prefix("KONSOLE_PROFILE_NAME=")
+                                .map(ToOwned::to_owned)
+                        })
+                        .unwrap_or_default();
                 }
 
                 if profile_name.is_empty() {
@@ -928,7 +1117,8 @@ impl Infos {
                     return "Monospace".to_owned();
                 }
 
-                let konsole_directory = Path::new(&self.local_dir).join("konsole");
+                let konsole_directory: PathBuf =
+                    self.environment.path(self.local_dir.join("konsole"));
                 if !konsole_directory.exists() {
                     return String::default();
                 }
@@ -1006,20 +1196,24 @@ impl Infos {
         #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
         {
             let mut de_name: String = String::default();
-            if env_exist("DESKTOP_SESSION") && get_env("DESKTOP_SESSION") == "regolith" {
+            if self.environment.env_exist("DESKTOP_SESSION")
+                && self.environment.get_env("DESKTOP_SESSION") == "regolith"
+            {
                 de_name = "Regolith".to_owned();
-            } else if env_exist("XDG_CURRENT_DESKTOP") {
-                de_name = get_env("XDG_CURRENT_DESKTOP")
+            } else if self.environment.env_exist("XDG_CURRENT_DESKTOP") {
+                de_name = self
+                    .environment
+                    .get_env("XDG_CURRENT_DESKTOP")
                     .replace("X-", "")
                     .replace("Gnome", "Budgie")
                     .replace("Budgie:GNOME", "Budgie");
-            } else if env_exist("DESKTOP_SESSION") {
-                de_name = get_env("DESKTOP_SESSION");
-            } else if env_exist("GNOME_DESKTOP_SESSION_ID") {
+            } else if self.environment.env_exist("DESKTOP_SESSION") {
+                de_name = self.environment.get_env("DESKTOP_SESSION");
+            } else if self.environment.env_exist("GNOME_DESKTOP_SESSION_ID") {
                 de_name = "Gnome".to_owned();
-            } else if env_exist("MATE_DESKTOP_SESSION_ID") {
+            } else if self.environment.env_exist("MATE_DESKTOP_SESSION_ID") {
                 de_name = "Mate".to_owned();
-            } else if env_exist("TDE_FULL_SESSION") {
+            } else if self.environment.env_exist("TDE_FULL_SESSION") {
                 de_name = "Trinity".to_owned();
             }
 
@@ -1036,8 +1230,8 @@ impl Infos {
             let mut version: String = String::default();
             match de_name.as_str() {
                 "Plasma" | "KDE" => {
-                    if command_exist("qdbus") {
-                        let file_to_parse: String = return_str_from_command(
+                    if self.command_exist("qdbus") {
+                        let file_to_parse: String = self.runner.output(
                             Command::new("qdbus")
                                 .arg("org.kde.KWin")
                                 .arg("/KWin")
@@ -1051,24 +1245,31 @@ impl Infos {
                         }
                     }
                     if version.is_empty() {
-                        version =
-                            return_str_from_command(Command::new("plasmashell").arg("--version"))
-                                .replace("plasmashell", "");
+                        version = self
+                            .runner
+                            .output(Command::new("plasmashell").arg("--version"))
+                            .replace("plasmashell", "");
                     }
                 }
                 "Mate" => {
-                    version =
-                        return_str_from_command(Command::new("mate-session").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("mate-session").arg("--version"));
                 }
                 "Gnome" => {
-                    version = return_str_from_command(Command::new("gnome-shell").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("gnome-shell").arg("--version"));
                 }
                 "Xfce" => {
-                    version =
-                        return_str_from_command(Command::new("xfce4-session").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("xfce4-session").arg("--version"));
                 }
                 "Deepin" => {
-                    version = get_file_content_without_lines("/etc/os-version")
+                    version = self
+                        .environment
+                        .get_file_content("/etc/os-version")
                         .lines()
                         .find(|line| line.starts_with("MajorVersion="))
                         .map(|line| line.split('=').nth(1).unwrap_or(""))
@@ -1076,25 +1277,32 @@ impl Infos {
                         .to_owned();
                 }
                 "Cinnamon" => {
-                    version = return_str_from_command(Command::new("cinnamon").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("cinnamon").arg("--version"));
                 }
                 "Budgie" => {
-                    version =
-                        return_str_from_command(Command::new("budgie-desktop").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("budgie-desktop").arg("--version"));
                 }
                 "LXQt" => {
-                    version =
-                        return_str_from_command(Command::new("lxqt-session").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("lxqt-session").arg("--version"));
                 }
                 "Lumina" => {
-                    version =
-                        return_str_from_command(Command::new("lumina-desktop").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("lumina-desktop").arg("--version"));
                 }
                 "Trinity" => {
-                    version = return_str_from_command(Command::new("tde-config").arg("--version"));
+                    version = self
+                        .runner
+                        .output(Command::new("tde-config").arg("--version"));
                 }
                 "Unity" => {
-                    version = return_str_from_command(Command::new("unity").arg("--version"));
+                    version = self.runner.output(Command::new("unity").arg("--version"));
                 }
                 &_ => {}
             }
@@ -1123,10 +1331,7 @@ impl Infos {
 
         #[cfg(all(not(target_os = "windows"), not(target_os = "macos")))]
         {
-            let gpu_cmd: String = match Command::new("lspci").args(["-mm"]).output() {
-                Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
-                Err(_) => return Vec::default(),
-            };
+            let gpu_cmd: String = self.runner.output(Command::new("lspci").args(["-mm"]));
             let mut gpus: Vec<String> = Vec::new();
             for line in gpu_cmd.lines().filter(|line| {
                 line.contains("Display") || line.contains("3D") || line.contains("VGA")
@@ -1136,6 +1341,9 @@ impl Infos {
                     .filter(|&s| !s.trim().is_empty())
                     .map(|s| s.trim())
                     .collect();
+                if parts.len() < 4 {
+                    continue;
+                }
                 let gpu: String = format!(
                     "{}{}",
                     parts[2].trim(),
@@ -1185,10 +1393,10 @@ impl Infos {
                         }
                         gpu.trim().to_owned()
                     }
-                    gpu if gpu.contains("NVIDIA") => format!(
-                        "NVIDIA {}",
-                        gpu.split('[').collect::<Vec<&str>>()[1].replace(']', "")
-                    ),
+                    gpu if gpu.contains("NVIDIA") => match gpu.split_once('[') {
+                        Some((_, model)) => format!("NVIDIA {}", model.replace(']', "")),
+                        None => gpu.replace("Corporation ", ""),
+                    },
                     gpu if gpu.contains("Intel") => {
                         let gpu: String = gpu
                             .replace("(R)", "")
@@ -1217,16 +1425,12 @@ impl Infos {
         {
             let mut gpus: Vec<String> = Vec::new();
 
-            let wmic_output_result = std::process::Command::new("wmic")
-                .args(&["path", "Win32_VideoController", "get", "caption"])
-                .output();
-            let wmic_output = if let Ok(wmic_output) = wmic_output_result {
-                wmic_output
-            } else {
-                return Vec::new();
-            };
-
-            let output_lines = String::from_utf8_lossy(&wmic_output.stdout);
+            let output_lines: String = self.runner.output(Command::new("wmic").args([
+                "path",
+                "Win32_VideoController",
+                "get",
+                "caption",
+            ]));
             let mut lines = output_lines.lines();
 
             while let Some(line) = lines.next() {
@@ -1,3 +1,5 @@
-pub mod getters;
+pub mod command;
+pub mod environment;
 pub mod infos;
 pub mod pid;
+pub mod report;
@@ -1,49 +1,64 @@
+use crate::system::environment::Environment;
 use std::fs;
-use std::path::Path;
-use sysinfo::{Pid, System};
-
-pub fn get_ppid(pid: &str) -> Option<String> {
-    let status_path = Path::new("/proc").join(pid).join("status");
-
-    if let Ok(status_content) = fs::read_to_string(status_path) {
-        for line in status_content.lines() {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() >= 2 && parts[0] == "PPid:" {
-                return Some(parts[1].to_owned());
-            }
-        }
-    }
 
-    None
+/// A field of `/proc/<pid>/status`, such as `PPid` or `Name`.
+fn get_status_field(environment: &Environment, pid: &str, field: &str) -> Option<String> {
+    let status_path = environment.path("/proc").join(pid).join("status");
+    let status_content: String = fs::read_to_string(status_path).ok()?;
+    status_content.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        (name == field).then(|| value.trim().to_owned())
+    })
 }
 
-pub fn get_parent_pid_names() -> Result<Vec<String>, String> {
-    let process_pid: Pid = Pid::from_u32(std::process::id());
+pub fn get_ppid(environment: &Environment, pid: &str) -> Option<String> {
+    get_status_field(environment, pid, "PPid").filter(|ppid| !ppid.is_empty())
+}
 
-    let mut system = System::new();
-    system.refresh_process(process_pid);
+/// The names of the first five parents of the `pid` process of the environment, the closest
+/// first.
+#[cfg(target_os = "linux")]
+pub fn get_parent_pid_names(environment: &Environment) -> Vec<String> {
+    let mut parent_names = Vec::new();
+    let mut current_pid: String = environment.pid.to_string();
 
-    let process = system
-        .process(process_pid)
-        .ok_or_else(|| format!("Process with PID {} not found", std::process::id()))?;
+    while parent_names.len() < 5 {
+        let Some(parent) = get_ppid(environment, &current_pid).filter(|ppid| ppid != "0") else {
+            break;
+        };
+        let Some(name) = get_status_field(environment, &parent, "Name") else {
+            break;
+        };
+        parent_names.push(name);
+        current_pid = parent;
+    }
 
+    parent_names
+}
+
+/// The names of the first five parents of the `pid` process of the environment, the closest
+/// first.
+#[cfg(not(target_os = "linux"))]
+pub fn get_parent_pid_names(environment: &Environment) -> Vec<String> {
+    use sysinfo::{Pid, System};
+
+    let mut system = System::new();
     let mut parent_names = Vec::new();
-    let mut current_pid = process.parent();
+    let mut current_pid = Some(Pid::from_u32(environment.pid));
 
-    while let Some(parent) = current_pid {
+    while let Some(pid) = current_pid {
+        system.refresh_process(pid);
+        let Some(process) = system.process(pid) else {
+            break;
+        };
+        if pid.as_u32() != environment.pid {
+            parent_names.push(process.name().to_owned());
+        }
         if parent_names.len() == 5 {
             break;
         }
-
-        system.refresh_process(parent);
-
-        let parent_process = system
-            .process(parent)
-            .ok_or_else(|| format!("Parent process with PID {} not found", parent.as_u32()))?;
-
-        parent_names.push(parent_process.name().to_owned());
-        current_pid = parent_process.parent();
+        current_pid = process.parent();
     }
 
-    Ok(parent_names)
+    parent_names
 }
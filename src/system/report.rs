@@ -0,0 +1,166 @@
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageManagerInfo {
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesktopInfo {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuInfo {
+    pub brand: String,
+    pub usage: Option<f32>,
+    /// In MHz.
+    pub frequency: u64,
+    pub cores: Option<usize>,
+    pub threads: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryInfo {
+    pub used: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    pub sent: u64,
+    pub received: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskInfo {
+    pub mount_point: String,
+    pub used: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskUsageInfo {
+    pub used: u64,
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatteryInfo {
+    pub percentage: f32,
+}
+
+/// The typed value gathered by an entry collector.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum EntryValue {
+    Text(String),
+    Duration(u64),
+    #[serde(serialize_with = "serialize_packages")]
+    Packages(Vec<PackageManagerInfo>),
+    Desktop(DesktopInfo),
+    Cpu(CpuInfo),
+    Gpus(Vec<String>),
+    Memory(MemoryInfo),
+    Network(NetworkInfo),
+    Disks(Vec<DiskInfo>),
+    DiskUsage(DiskUsageInfo),
+    Battery(BatteryInfo),
+    /// The entry missed its deadline, only reported when `timeout.show_timed_out` is set.
+    TimedOut,
+}
+
+/// Serializes the packages as an object of counts, summing those of the package managers listed
+/// more than once, e.g. `pkg` on FreeBSD.
+fn serialize_packages<S: Serializer>(
+    packages: &[PackageManagerInfo],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for package_manager in packages {
+        *counts.entry(&package_manager.name).or_default() += package_manager.count;
+    }
+    serializer.collect_map(counts)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportEntry {
+    pub id: String,
+    pub label_key: String,
+    pub value: EntryValue,
+}
+
+/// Everything afetch knows about the system, without any translation or styling.
+///
+/// Entries are kept in the order of the registry that produced them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FetchReport {
+    pub username: String,
+    pub hostname: String,
+    pub entries: Vec<ReportEntry>,
+}
+
+impl FetchReport {
+    pub fn get(&self, id: &str) -> Option<&EntryValue> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| &entry.value)
+    }
+}
+
+/// The keys of the JSON output taken from the builtin entries, in order, along with the id of
+/// the entry each one comes from. The `disks` entry is left out, its totals being those of the
+/// disks listed by `disk`.
+pub const JSON_KEYS: [(&str, &str); 18] = [
+    ("os", "os"),
+    ("host", "host"),
+    ("kernel", "kernel"),
+    ("uptime", "uptime"),
+    ("packages", "packages"),
+    ("shell", "shell"),
+    ("resolution", "resolution"),
+    ("desktop", "desktop"),
+    ("wm", "wm"),
+    ("terminal", "terminal"),
+    ("terminal_font", "terminal-font"),
+    ("cpu", "cpu"),
+    ("gpus", "gpu"),
+    ("memory", "memory"),
+    ("network", "network"),
+    ("disks", "disk"),
+    ("public_ip", "public-ip"),
+    ("battery", "battery"),
+];
+
+/// Whether `id` is the id of a builtin entry, whose value is printed under one of the
+/// [`JSON_KEYS`] rather than in the `custom` object.
+fn is_builtin(id: &str) -> bool {
+    id == "disks" || JSON_KEYS.iter().any(|(_, entry_id)| *entry_id == id)
+}
+
+/// The JSON object is the same whatever the configuration: the value of each of the
+/// [`JSON_KEYS`] is `null` when its entry is disabled or has no value, and the custom entries
+/// are gathered in a `custom` object by id.
+impl Serialize for FetchReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(JSON_KEYS.len() + 3))?;
+        map.serialize_entry("username", &self.username)?;
+        map.serialize_entry("hostname", &self.hostname)?;
+        for (key, id) in JSON_KEYS {
+            map.serialize_entry(key, &self.get(id))?;
+        }
+        let custom: BTreeMap<&str, &EntryValue> = self
+            .entries
+            .iter()
+            .filter(|entry| !is_builtin(&entry.id))
+            .map(|entry| (entry.id.as_str(), &entry.value))
+            .collect();
+        map.serialize_entry("custom", &custom)?;
+        map.end()
+    }
+}
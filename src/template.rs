@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Part {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// A format string such as `{used} / {total}`; `{{` and `}}` print literal braces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    UnclosedPlaceholder,
+    UnexpectedClosingBrace,
+    EmptyPlaceholder,
+    UnknownPlaceholder {
+        placeholder: String,
+        available: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedPlaceholder => write!(f, "a `{{` is never closed"),
+            Self::UnexpectedClosingBrace => {
+                write!(f, "unexpected `}}`, use `}}}}` to print a brace")
+            }
+            Self::EmptyPlaceholder => write!(f, "empty placeholder `{{}}`"),
+            Self::UnknownPlaceholder {
+                placeholder,
+                available,
+            } => write!(
+                f,
+                "unknown placeholder `{{{}}}`, available placeholders are: {}",
+                placeholder,
+                available
+                    .iter()
+                    .map(|available| format!("{{{}}}", available))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl Template {
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts: Vec<Part> = Vec::new();
+        let mut literal: String = String::default();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut placeholder: String = String::default();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder),
+                        }
+                    }
+                    let placeholder: String = placeholder.trim().to_owned();
+                    if placeholder.is_empty() {
+                        return Err(TemplateError::EmptyPlaceholder);
+                    }
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Part::Placeholder(placeholder));
+                }
+                '}' => return Err(TemplateError::UnexpectedClosingBrace),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Self { parts })
+    }
+
+    /// Parses the template and checks that it only uses the given placeholders.
+    pub fn parse_with_placeholders(
+        template: &str,
+        available: &[&'static str],
+    ) -> Result<Self, TemplateError> {
+        let template: Self = Self::parse(template)?;
+        for placeholder in template.placeholders() {
+            if !available.contains(&placeholder) {
+                return Err(TemplateError::UnknownPlaceholder {
+                    placeholder: placeholder.to_owned(),
+                    available: available.to_vec(),
+                });
+            }
+        }
+        Ok(template)
+    }
+
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Placeholder(placeholder) => Some(placeholder.as_str()),
+            Part::Literal(_) => None,
+        })
+    }
+
+    /// Fills the template, placeholders without a value are replaced by an empty string.
+    pub fn render(&self, values: &HashMap<&str, String>) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Literal(literal) => literal.as_str(),
+                Part::Placeholder(placeholder) => values
+                    .get(placeholder.as_str())
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A share of the total time above which a timing is marked as dominating it.
+const DOMINANT_SHARE: f64 = 0.5;
+const MAX_NAME_LENGTH: usize = 48;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimingKind {
+    Step,
+    Entry,
+    Command,
+}
+
+impl TimingKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Step => "step",
+            Self::Entry => "entry",
+            Self::Command => "command",
+        }
+    }
+}
+
+/// The wall time spent on a step of afetch, an entry or an external command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub kind: TimingKind,
+    pub name: String,
+    pub duration: Duration,
+    /// Why the duration does not reflect the real work, e.g. `cached` or `timed out`.
+    pub note: Option<&'static str>,
+}
+
+/// The recorded timings, `None` while `--timings` is not given so that recording costs nothing.
+static TIMINGS: Mutex<Option<Vec<Timing>>> = Mutex::new(None);
+
+pub fn enable() {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings.get_or_insert_with(Vec::new);
+    }
+}
+
+pub fn is_enabled() -> bool {
+    TIMINGS.lock().is_ok_and(|timings| timings.is_some())
+}
+
+pub fn record(kind: TimingKind, name: &str, duration: Duration, note: Option<&'static str>) {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        if let Some(timings) = timings.as_mut() {
+            timings.push(Timing {
+                kind,
+                name: name.to_owned(),
+                duration,
+                note,
+            });
+        }
+    }
+}
+
+/// The recorded timings, steps first in the order they ran, then entries and commands from the
+/// slowest to the fastest.
+pub fn take() -> Vec<Timing> {
+    let mut timings: Vec<Timing> = TIMINGS
+        .lock()
+        .ok()
+        .and_then(|mut timings| timings.take())
+        .unwrap_or_default();
+    timings.sort_by(|a, b| {
+        a.kind.cmp(&b.kind).then_with(|| {
+            if a.kind == TimingKind::Step {
+                std::cmp::Ordering::Equal
+            } else {
+                b.duration.cmp(&a.duration)
+            }
+        })
+    });
+    timings
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// A table of the timings, the ones that take at least half of `total` being marked with `◀`.
+pub fn render(timings: &[Timing], total: Duration) -> String {
+    let mut output: String = format!("Timings (total: {}):\n", format_duration(total));
+    for timing in timings {
+        let name: String = if timing.name.chars().count() > MAX_NAME_LENGTH {
+            format!(
+                "{}…",
+                timing
+                    .name
+                    .chars()
+                    .take(MAX_NAME_LENGTH - 1)
+                    .collect::<String>()
+            )
+        } else {
+            timing.name.clone()
+        };
+        let dominant: bool = timing.duration.as_secs_f64() >= total.as_secs_f64() * DOMINANT_SHARE;
+        writeln!(
+            output,
+            "  {:<8} {:<width$} {:>10}{}{}",
+            timing.kind.as_str(),
+            name,
+            format_duration(timing.duration),
+            timing
+                .note
+                .map(|note| format!(" ({})", note))
+                .unwrap_or_default(),
+            if dominant { "  ◀" } else { "" },
+            width = MAX_NAME_LENGTH
+        )
+        .ok();
+    }
+    output
+}
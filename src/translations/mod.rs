@@ -1,17 +1,239 @@
-use crate::translations::english::english;
-use crate::translations::french::french;
-use std::collections::HashMap;
+use crate::translations::plural::{PluralCategory, PluralRules};
+use serde_yaml::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 
-pub mod english;
-pub mod french;
+pub mod plural;
 
-pub fn get_language(country_code: &str) -> HashMap<&'static str, &'static str> {
-    match country_code {
-        "fr" => french(),
-        _ => english(),
+/// The translation the others fall back to, it defines every key.
+pub const FALLBACK_CODE: &str = "en";
+
+/// The key of the plural rules in a translation file, see [`PluralRules`].
+pub const PLURAL_RULES_KEY: &str = "plural-rules";
+
+/// The translations shipped with afetch, by language code.
+const BUNDLED: [(&str, &str); 2] = [
+    ("en", include_str!("en.yaml")),
+    ("fr", include_str!("fr.yaml")),
+];
+
+/// The directory of the user translations, `~/.config/afetch/lang/<code>.yaml`.
+pub fn user_translations_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|config_dir| config_dir.join("afetch").join("lang"))
+}
+
+/// A string of a translation, or the forms of a string depending on a count, by plural category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Plural(BTreeMap<PluralCategory, String>),
+}
+
+fn parse_message(value: Value) -> Result<Message, String> {
+    match value {
+        Value::String(text) => Ok(Message::Text(text)),
+        Value::Mapping(forms) => forms
+            .into_iter()
+            .map(|(category, form)| match (category, form) {
+                (Value::String(category), Value::String(form)) => Ok((category.parse()?, form)),
+                _ => Err("the plural forms have to be strings".to_owned()),
+            })
+            .collect::<Result<BTreeMap<PluralCategory, String>, String>>()
+            .map(Message::Plural),
+        _ => Err("expected a string or a map of plural forms".to_owned()),
+    }
+}
+
+fn parse(content: &str, origin: &str) -> Result<HashMap<String, Message>, String> {
+    serde_yaml::from_str::<Option<HashMap<String, Value>>>(content)
+        .map_err(|error| format!("{}: {}", origin, error))?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(key, value)| {
+            parse_message(value)
+                .map(|message| (key.clone(), message))
+                .map_err(|error| format!("{}: `{}`: {}", origin, key, error))
+        })
+        .collect()
+}
+
+fn bundled(code: &str) -> HashMap<String, Message> {
+    BUNDLED
+        .iter()
+        .find(|(bundled_code, _)| *bundled_code == code)
+        // The bundled files are checked by the tests.
+        .and_then(|(_, content)| parse(content, code).ok())
+        .unwrap_or_default()
+}
+
+/// The messages of a language along with its plural rules.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Messages {
+    messages: HashMap<String, Message>,
+    rules: PluralRules,
+}
+
+impl Messages {
+    /// The messages of `code`, the user file overriding the bundled one key by key. The plural
+    /// rules are `default_rules` when neither defines them.
+    fn load(
+        code: &str,
+        user_dir: Option<&Path>,
+        default_rules: &PluralRules,
+    ) -> Result<Self, String> {
+        let mut messages: HashMap<String, Message> = bundled(code);
+        let mut origin: String = code.to_owned();
+        if let Some(path) = user_dir.map(|user_dir| user_dir.join(format!("{}.yaml", code))) {
+            if path.exists() {
+                origin = path.display().to_string();
+                let content: String = std::fs::read_to_string(&path)
+                    .map_err(|error| format!("{}: {}", origin, error))?;
+                messages.extend(parse(&content, &origin)?);
+            }
+        }
+
+        let rules: PluralRules = match messages.get(PLURAL_RULES_KEY) {
+            Some(Message::Plural(rules)) => PluralRules::parse(
+                rules
+                    .iter()
+                    .map(|(category, rule)| (*category, rule.as_str())),
+            )
+            .map_err(|error| format!("{}: {}", origin, error))?,
+            Some(Message::Text(_)) => {
+                return Err(format!(
+                    "{}: `{}` has to map plural categories to their rule",
+                    origin, PLURAL_RULES_KEY
+                ))
+            }
+            None => default_rules.clone(),
+        };
+        Ok(Self { messages, rules })
+    }
+
+    /// The string of `key`, the `other` form of a plural one.
+    fn text(&self, key: &str) -> Option<&str> {
+        match self.messages.get(key)? {
+            Message::Text(text) => Some(text),
+            Message::Plural(forms) => forms.get(&PluralCategory::Other).map(String::as_str),
+        }
+    }
+
+    /// The form of `key` for `count`, `other` standing for the forms that are not defined.
+    fn plural(&self, key: &str, count: u64) -> Option<&str> {
+        match self.messages.get(key)? {
+            Message::Text(text) => Some(text),
+            Message::Plural(forms) => forms
+                .get(&self.rules.category(count))
+                .or_else(|| forms.get(&PluralCategory::Other))
+                .map(String::as_str),
+        }
     }
 }
 
-pub fn language_code_list() -> [&'static str; 2] {
-    ["fr", "en"]
+/// The codes of the bundled translations and of the user ones, sorted.
+pub fn language_code_list() -> Vec<String> {
+    language_codes(user_translations_dir().as_deref())
+}
+
+pub fn language_codes(user_dir: Option<&Path>) -> Vec<String> {
+    let mut codes: Vec<String> = BUNDLED.iter().map(|(code, _)| (*code).to_owned()).collect();
+    if let Some(entries) = user_dir.and_then(|user_dir| std::fs::read_dir(user_dir).ok()) {
+        codes.extend(entries.filter_map(|entry| {
+            let path: PathBuf = entry.ok()?.path();
+            if path.extension()? != "yaml" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_owned())
+        }));
+    }
+    codes.sort();
+    codes.dedup();
+    codes
+}
+
+/// The strings of a language, the keys it does not define being taken from English.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Translation {
+    pub code: String,
+    own: Messages,
+    fallback: Messages,
+}
+
+impl Translation {
+    /// Loads the translation of `code` from the bundled files and the user translations.
+    pub fn load(code: &str) -> Result<Self, String> {
+        Self::load_from(code, user_translations_dir().as_deref())
+    }
+
+    pub fn load_from(code: &str, user_dir: Option<&Path>) -> Result<Self, String> {
+        let fallback: Messages = Messages::load(FALLBACK_CODE, user_dir, &PluralRules::default())?;
+        Ok(Self {
+            code: code.to_owned(),
+            own: Messages::load(code, user_dir, &fallback.rules)?,
+            fallback,
+        })
+    }
+
+    /// The string of `key`, the key itself when English does not define it either.
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        self.own
+            .text(key)
+            .or_else(|| self.fallback.text(key))
+            .unwrap_or(key)
+    }
+
+    /// The form of `key` for `count`, with `{count}` replaced by it.
+    pub fn plural(&self, key: &str, count: u64) -> String {
+        self.own
+            .plural(key, count)
+            .or_else(|| self.fallback.plural(key, count))
+            .unwrap_or(key)
+            .replace("{count}", &count.to_string())
+    }
+
+    /// `value` with `precision` decimals and the decimal separator of the language.
+    pub fn decimal(&self, value: f64, precision: usize) -> String {
+        format!("{:.*}", precision, value).replace('.', self.get("decimal-separator"))
+    }
+
+    /// The items separated with the list separator of the language, e.g. `, `.
+    pub fn list(&self, items: &[String]) -> String {
+        items.join(self.get("list-separator"))
+    }
+
+    /// The English keys this translation does not define, sorted. The plural forms it lacks are
+    /// listed as `<key>.<category>`.
+    pub fn missing_keys(&self) -> Vec<String> {
+        let categories: Vec<PluralCategory> = self.own.rules.categories();
+        let mut keys: Vec<String> = Vec::new();
+        for (key, fallback_message) in &self.fallback.messages {
+            match (self.own.messages.get(key), fallback_message) {
+                (None, _) => keys.push(key.clone()),
+                (Some(Message::Plural(forms)), Message::Plural(_)) if key != PLURAL_RULES_KEY => {
+                    keys.extend(
+                        categories
+                            .iter()
+                            .filter(|category| !forms.contains_key(category))
+                            .map(|category| format!("{}.{}", key, category)),
+                    );
+                }
+                _ => {}
+            }
+        }
+        keys.sort_unstable();
+        keys
+    }
+
+    /// The keys this translation defines that English does not, usually misspelled ones.
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self
+            .own
+            .messages
+            .keys()
+            .filter(|key| !self.fallback.messages.contains_key(*key))
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+        keys
+    }
 }
@@ -0,0 +1,211 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A CLDR plural category, in the order the rules are tried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+const CATEGORIES: [(&str, PluralCategory); 6] = [
+    ("zero", PluralCategory::Zero),
+    ("one", PluralCategory::One),
+    ("two", PluralCategory::Two),
+    ("few", PluralCategory::Few),
+    ("many", PluralCategory::Many),
+    ("other", PluralCategory::Other),
+];
+
+impl FromStr for PluralCategory {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        CATEGORIES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, category)| *category)
+            .ok_or_else(|| {
+                format!(
+                    "unknown plural category `{}`, expected one of `{}`",
+                    value,
+                    CATEGORIES
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .collect::<Vec<&str>>()
+                        .join("`, `")
+                )
+            })
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: &str = CATEGORIES
+            .iter()
+            .find(|(_, category)| category == self)
+            .map(|(name, _)| *name)
+            .unwrap_or_default();
+        write!(f, "{}", name)
+    }
+}
+
+/// A relation such as `n % 10 = 2..4`, the ranges being inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Relation {
+    operand: char,
+    modulo: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Relation {
+    fn parse(relation: &str) -> Result<Self, String> {
+        // `is`, `within` and `=` are the same as `in` for the integers afetch counts.
+        let normalized: String = relation
+            .replace("!=", " not in ")
+            .replace('=', " in ")
+            .replace(" is not ", " not in ")
+            .replace(" is ", " in ")
+            .replace(" within ", " in ");
+        let tokens: Vec<&str> = normalized.split_whitespace().collect();
+        let keyword: usize = tokens
+            .iter()
+            .position(|token| *token == "in" || *token == "not")
+            .ok_or_else(|| format!("`{}` has no operator", relation.trim()))?;
+        let negated: bool = tokens[keyword] == "not";
+        let ranges_start: usize = if negated { keyword + 2 } else { keyword + 1 };
+        if negated && tokens.get(keyword + 1) != Some(&"in") {
+            return Err(format!("`{}` has no operator", relation.trim()));
+        }
+
+        let (operand, modulo): (char, Option<u64>) = match &tokens[..keyword] {
+            [operand] => (operand_char(operand)?, None),
+            [operand, "%", modulo] => (
+                operand_char(operand)?,
+                Some(
+                    parse_number(modulo)
+                        .filter(|modulo| *modulo != 0)
+                        .ok_or_else(|| format!("`{}` is not a valid modulo", modulo))?,
+                ),
+            ),
+            _ => return Err(format!("`{}` is not a valid expression", relation.trim())),
+        };
+
+        let ranges: Vec<(u64, u64)> = tokens[ranges_start..]
+            .concat()
+            .split(',')
+            .map(|range| {
+                let (start, end): (&str, &str) = range.split_once("..").unwrap_or((range, range));
+                match (parse_number(start), parse_number(end)) {
+                    (Some(start), Some(end)) if start <= end => Ok((start, end)),
+                    _ => Err(format!("`{}` is not a valid range", range)),
+                }
+            })
+            .collect::<Result<Vec<(u64, u64)>, String>>()?;
+
+        Ok(Self {
+            operand,
+            modulo,
+            negated,
+            ranges,
+        })
+    }
+
+    fn matches(&self, count: u64) -> bool {
+        // The visible fraction digits and the exponent operands are always 0 for an integer.
+        let mut value: u64 = match self.operand {
+            'n' | 'i' => count,
+            _ => 0,
+        };
+        if let Some(modulo) = self.modulo {
+            value %= modulo;
+        }
+        let in_ranges: bool = self
+            .ranges
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&value));
+        in_ranges != self.negated
+    }
+}
+
+fn operand_char(operand: &str) -> Result<char, String> {
+    match operand {
+        "n" | "i" | "v" | "w" | "f" | "t" | "c" | "e" => Ok(operand.chars().next().unwrap_or('n')),
+        _ => Err(format!("unknown operand `{}`", operand)),
+    }
+}
+
+fn parse_number(number: &str) -> Option<u64> {
+    number.trim().parse().ok()
+}
+
+/// A condition of a plural rule, `or` of `and`s of relations.
+type Condition = Vec<Vec<Relation>>;
+
+/// The plural rules of a language, written with the CLDR syntax, e.g. for Polish:
+///
+/// ```yaml
+/// plural-rules:
+///   one: "i = 1 and v = 0"
+///   few: "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"
+///   many: "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9"
+/// ```
+///
+/// Only integers are counted, the samples following `@` are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PluralRules {
+    rules: BTreeMap<PluralCategory, Condition>,
+}
+
+impl PluralRules {
+    pub fn parse<'a, I: IntoIterator<Item = (PluralCategory, &'a str)>>(
+        rules: I,
+    ) -> Result<Self, String> {
+        let mut parsed: BTreeMap<PluralCategory, Condition> = BTreeMap::new();
+        for (category, rule) in rules {
+            let rule: &str = rule.split('@').next().unwrap_or_default().trim();
+            if category == PluralCategory::Other || rule.is_empty() {
+                continue;
+            }
+            let condition: Condition = rule
+                .split(" or ")
+                .map(|and_condition| {
+                    and_condition
+                        .split(" and ")
+                        .map(Relation::parse)
+                        .collect::<Result<Vec<Relation>, String>>()
+                })
+                .collect::<Result<Condition, String>>()
+                .map_err(|error| format!("plural rule `{}`: {}", category, error))?;
+            parsed.insert(category, condition);
+        }
+        Ok(Self { rules: parsed })
+    }
+
+    /// The category of `count`, `other` when no rule matches.
+    pub fn category(&self, count: u64) -> PluralCategory {
+        self.rules
+            .iter()
+            .find(|(_, condition)| {
+                condition
+                    .iter()
+                    .any(|relations| relations.iter().all(|relation| relation.matches(count)))
+            })
+            .map_or(PluralCategory::Other, |(category, _)| *category)
+    }
+
+    /// The categories a plural message of this language has to define, `other` included.
+    pub fn categories(&self) -> Vec<PluralCategory> {
+        self.rules
+            .keys()
+            .copied()
+            .chain(std::iter::once(PluralCategory::Other))
+            .collect()
+    }
+}
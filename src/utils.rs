@@ -1,84 +1,269 @@
-use std::collections::HashMap;
-use std::path::Path;
-use std::process::Command;
+use crate::timings::{self, TimingKind};
+use crate::translations::Translation;
+use std::cell::Cell;
+use std::io::Read;
+use std::process::{Child, Command, Stdio};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
 
 pub const fn div_mod(dividend: u64, divisor: u64) -> (u64, u64) {
     (dividend / divisor, dividend % divisor)
 }
 
+/// The instant after which external commands are killed, see [`set_command_deadline`].
+static COMMAND_DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+
+thread_local! {
+    /// A shorter deadline for the commands run by the current thread, see
+    /// [`with_command_deadline`].
+    static THREAD_DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// The process ids of the running commands along with their deadline.
+static RUNNING_COMMANDS: Mutex<Vec<(u32, Instant)>> = Mutex::new(Vec::new());
+
+/// Kills the external commands that are still running at `deadline`, so that a hung program
+/// cannot keep afetch from exiting.
+pub fn set_command_deadline(deadline: Instant) {
+    if let Ok(mut command_deadline) = COMMAND_DEADLINE.lock() {
+        *command_deadline = Some(deadline);
+    }
+}
+
+/// Runs `f` with the commands it starts on the current thread being killed at `deadline`,
+/// without exceeding the global command deadline.
+pub fn with_command_deadline<T>(deadline: Option<Instant>, f: impl FnOnce() -> T) -> T {
+    let previous: Option<Instant> = THREAD_DEADLINE.with(|thread| thread.replace(deadline));
+    let result: T = f();
+    THREAD_DEADLINE.with(|thread| thread.set(previous));
+    result
+}
+
+/// The instant after which the commands run by the current thread are killed, if any.
+pub fn command_deadline() -> Option<Instant> {
+    let global: Option<Instant> = COMMAND_DEADLINE.lock().ok().and_then(|deadline| *deadline);
+    match (global, THREAD_DEADLINE.with(Cell::get)) {
+        (Some(global), Some(thread)) => Some(global.min(thread)),
+        (global, thread) => global.or(thread),
+    }
+}
+
+/// The time left before the command deadline, if one is set.
+pub fn remaining_time() -> Option<Duration> {
+    command_deadline().map(|deadline| deadline.saturating_duration_since(Instant::now()))
+}
+
+/// Kills the running commands whose deadline has passed, without waiting for the threads that
+/// run them to notice it.
+pub fn kill_overdue_commands() {
+    if let Ok(running) = RUNNING_COMMANDS.lock() {
+        let now: Instant = Instant::now();
+        for (pid, _) in running.iter().filter(|(_, deadline)| *deadline <= now) {
+            kill_process_group(*pid);
+        }
+    }
+}
+
+/// The program and arguments of a command separated by spaces, newlines being escaped.
+pub fn command_line(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| arg.to_string_lossy().replace('\n', "\\n"))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn record_command(command: &Command, duration: Duration, note: Option<&'static str>) {
+    if timings::is_enabled() {
+        timings::record(TimingKind::Command, &command_line(command), duration, note);
+    }
+}
+
 pub fn return_str_from_command(command: &mut Command) -> String {
-    match command.output() {
+    if let Some(remaining_time) = remaining_time() {
+        return return_str_from_command_with_timeout(command, remaining_time);
+    }
+    let started: Instant = Instant::now();
+    let output: String = match command.output() {
         Ok(output) => String::from_utf8_lossy(&output.stdout).to_string(),
         Err(_) => String::default(),
-    }
+    };
+    record_command(command, started.elapsed(), None);
+    output
 }
 
-pub fn get_file_content_without_lines(file_path: impl AsRef<Path>) -> String {
-    std::fs::read_to_string(file_path)
-        .unwrap_or_default()
-        .replace('\n', "")
-}
-pub fn get_file_content(file_path: impl AsRef<Path>) -> String {
-    std::fs::read_to_string(file_path).unwrap_or_default()
+/// Same as [`return_str_from_command`], but kills the command if it is still running after
+/// `timeout` (or at the command deadline if it comes first), in which case an empty string is
+/// returned.
+pub fn return_str_from_command_with_timeout(command: &mut Command, timeout: Duration) -> String {
+    let timeout: Duration = remaining_time().map_or(timeout, |remaining| remaining.min(timeout));
+    let started: Instant = Instant::now();
+    let output: Option<String> = run_with_timeout(command, timeout);
+    record_command(
+        command,
+        started.elapsed(),
+        output.is_none().then_some("killed"),
+    );
+    output.unwrap_or_default()
 }
 
-pub fn command_exist(program: &str) -> bool {
-    which::which(program).is_ok()
+/// How long the output of a command is still read once it has exited, as the processes it has
+/// started in the background may keep its output open.
+const OUTPUT_GRACE_PERIOD: Duration = Duration::from_millis(50);
+
+/// Kills the processes of the group led by `pid`, which hold the command and the processes it
+/// has started.
+fn kill_process_group(pid: u32) {
+    #[cfg(unix)]
+    if let Ok(pid) = libc::pid_t::try_from(pid) {
+        // SAFETY: `kill` has no memory effect, a negative pid targets the process group.
+        unsafe { libc::kill(-pid, libc::SIGKILL) };
+    }
+    #[cfg(not(unix))]
+    let _ = pid;
 }
 
-pub fn format_time(time_to_format: u64, language: &HashMap<&'static str, &'static str>) -> String {
-    let (minutes, seconds): (u64, u64) = div_mod(time_to_format, 60);
-    let (hours, minutes): (u64, u64) = div_mod(minutes, 60);
-    let (days, hours): (u64, u64) = div_mod(hours, 24);
-    let mut time_formatted: Vec<String> = Vec::new();
+/// Kills the command along with the processes it has started.
+fn kill(child: &mut Child) {
+    kill_process_group(child.id());
+    child.kill().ok();
+    child.wait().ok();
+}
 
-    match days {
-        0 => (),
-        1 => time_formatted.push(format!("{} {}", days, language["day"])),
-        _ => time_formatted.push(format!("{} {}", days, language["days"])),
+/// The output of the command, `None` if it has been killed after `timeout`.
+fn run_with_timeout(command: &mut Command, timeout: Duration) -> Option<String> {
+    if timeout.is_zero() {
+        return None;
+    }
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(command, 0);
+    let deadline: Instant = Instant::now() + timeout;
+    let mut child: Child = match command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return Some(String::default()),
+    };
+    if let Ok(mut running) = RUNNING_COMMANDS.lock() {
+        running.push((child.id(), deadline));
+    }
+    let output: Option<String> = wait_with_deadline(&mut child, deadline);
+    if let Ok(mut running) = RUNNING_COMMANDS.lock() {
+        running.retain(|(pid, _)| *pid != child.id());
     }
+    output
+}
 
-    match hours {
-        0 => (),
-        1 => time_formatted.push(format!("{} {}", hours, language["hour"])),
-        _ => time_formatted.push(format!("{} {}", hours, language["hours"])),
+/// Reads the output of `child` until it exits, killing it at `deadline`.
+fn wait_with_deadline(child: &mut Child, deadline: Instant) -> Option<String> {
+    let output: Arc<Mutex<Vec<u8>>> = Arc::default();
+    let (read_sender, read) = mpsc::channel::<()>();
+    if let Some(mut stdout) = child.stdout.take() {
+        let output: Arc<Mutex<Vec<u8>>> = Arc::clone(&output);
+        std::thread::spawn(move || {
+            let mut buffer: [u8; 4096] = [0; 4096];
+            while let Ok(length @ 1..) = stdout.read(&mut buffer) {
+                if let Ok(mut output) = output.lock() {
+                    output.extend_from_slice(&buffer[..length]);
+                }
+            }
+            read_sender.send(()).ok();
+        });
     }
 
-    match minutes {
-        0 => (),
-        1 => time_formatted.push(format!("{} {}", minutes, language["minute"])),
-        _ => time_formatted.push(format!("{} {}", minutes, language["minutes"])),
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => break,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(5)),
+            _ => {
+                kill(child);
+                return None;
+            }
+        }
     }
 
-    if seconds > 0 && minutes == 0 && hours == 0 {
-        match minutes {
-            0 => (),
-            1 => time_formatted.push(format!("{} {}", seconds, language["second"])),
-            _ => time_formatted.push(format!("{} {}", seconds, language["seconds"])),
+    // The reader is left behind if the output is still open after the grace period.
+    read.recv_timeout(OUTPUT_GRACE_PERIOD).ok();
+    let output: Vec<u8> = output
+        .lock()
+        .map(|output| output.clone())
+        .unwrap_or_default();
+    Some(String::from_utf8_lossy(&output).to_string())
+}
+
+/// A duration such as `2 days, 3 hours, 5 minutes`, the seconds only being shown under a minute.
+pub fn format_time(time_to_format: u64, language: &Translation) -> String {
+    let (minutes, seconds): (u64, u64) = div_mod(time_to_format, 60);
+    let (hours, minutes): (u64, u64) = div_mod(minutes, 60);
+    let (days, hours): (u64, u64) = div_mod(hours, 24);
+    let mut time_formatted: Vec<String> = Vec::new();
+
+    for (count, key) in [(days, "days"), (hours, "hours"), (minutes, "minutes")] {
+        if count > 0 {
+            time_formatted.push(language.plural(key, count));
         }
     }
-    time_formatted.join(", ")
+    if time_to_format < 60 && seconds > 0 {
+        time_formatted.push(language.plural("seconds", seconds));
+    }
+    language.list(&time_formatted)
 }
 
 // Based on the human_bytes library of Forkbomb9: https://gitlab.com/forkbomb9/human_bytes-rs
-pub fn convert_to_readable_unity<T: Into<f64>>(size: T) -> String {
-    const SUFFIX: [&str; 9] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+pub fn convert_to_readable_unity<T: Into<f64>>(size: T, language: &Translation) -> String {
+    const SUFFIX: [&str; 9] = [
+        "unit-byte",
+        "unit-kilobyte",
+        "unit-megabyte",
+        "unit-gigabyte",
+        "unit-terabyte",
+        "unit-petabyte",
+        "unit-exabyte",
+        "unit-zettabyte",
+        "unit-yottabyte",
+    ];
     let size_converted: f64 = size.into();
     if size_converted <= 0.0_f64 {
-        return "0 B".to_owned();
+        return format!("0 {}", language.get(SUFFIX[0]));
     }
     let base: f64 = size_converted.log10() / 1024_f64.log10();
-    let mut result: String = format!("{:.1}", 1024_f64.powf(base - base.floor()))
-        .trim_end_matches(".0")
-        .to_owned();
-    result.push_str(SUFFIX[base.floor() as usize]);
+    let value: f64 = 1024_f64.powf(base - base.floor());
+    let mut result: String = if format!("{:.1}", value).ends_with(".0") {
+        language.decimal(value, 0)
+    } else {
+        language.decimal(value, 1)
+    };
+    result.push_str(language.get(SUFFIX[base.floor() as usize]));
     result
 }
 
-pub fn env_exist(env_var: &str) -> bool {
-    std::env::var(env_var).is_ok()
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current: Vec<usize> = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution: usize = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
 }
 
-pub fn get_env(env_var: &str) -> String {
-    std::env::var(env_var).unwrap_or_default()
+/// The candidate closest to `value`, if it is close enough to be a likely typo.
+pub fn closest_match<'a, I: IntoIterator<Item = &'a str>>(
+    value: &str,
+    candidates: I,
+) -> Option<&'a str> {
+    let max_distance: usize = (value.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(value, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
 }
@@ -0,0 +1,164 @@
+use afetch::cache::{Cache, CachePolicy};
+use afetch::config::{Config, EntryId};
+use afetch::entries::Registry;
+use afetch::system::environment::Environment;
+use afetch::system::report::EntryValue;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// A cache stored in its own file, so that the tests do not share theirs.
+fn cache(name: &str, config: &Config) -> (Cache, PathBuf) {
+    let path: PathBuf = std::env::temp_dir().join(format!("afetch-cache-{}.json", name));
+    std::fs::remove_file(&path).ok();
+    (
+        Cache::load_from(Some(path.clone()), Path::new("/"), config, false),
+        path,
+    )
+}
+
+fn policy(watched: Vec<PathBuf>, env: &[&str]) -> CachePolicy {
+    CachePolicy {
+        ttl: Duration::from_secs(60),
+        watched,
+        env: env.iter().map(|name| (*name).to_owned()).collect(),
+    }
+}
+
+fn text(value: &str) -> EntryValue {
+    EntryValue::Text(value.to_owned())
+}
+
+#[test]
+fn values_are_kept_between_runs() {
+    let config: Config = Config::default();
+    let (cache, path) = cache("runs", &config);
+    let policy: CachePolicy = policy(Vec::new(), &[]);
+    assert_eq!(cache.get("shell", &policy), None);
+
+    cache.insert("shell", &policy, &text("zsh"));
+    assert_eq!(cache.get("shell", &policy), Some(text("zsh")));
+    cache.save();
+
+    let cache: Cache = Cache::load_from(Some(path.clone()), Path::new("/"), &config, false);
+    assert_eq!(cache.get("shell", &policy), Some(text("zsh")));
+    assert_eq!(cache.get("kernel", &policy), None);
+    // `--refresh` ignores the stored values.
+    let cache: Cache = Cache::load_from(Some(path), Path::new("/"), &config, true);
+    assert_eq!(cache.get("shell", &policy), None);
+    assert_eq!(
+        Cache::disabled().get("shell", &policy),
+        None,
+        "a disabled cache has no value"
+    );
+}
+
+#[test]
+fn values_expire_after_their_ttl() {
+    let (cache, _) = cache("ttl", &Config::default());
+    let mut policy: CachePolicy = policy(Vec::new(), &[]);
+    cache.insert("packages", &policy, &text("pacman"));
+    policy.ttl = Duration::ZERO;
+    assert_eq!(cache.get("packages", &policy), None);
+}
+
+#[test]
+fn values_expire_when_a_watched_file_changes() {
+    let watched: PathBuf = std::env::temp_dir().join("afetch-cache-watched");
+    let missing: PathBuf = std::env::temp_dir().join("afetch-cache-missing");
+    std::fs::write(&watched, "packages").unwrap();
+    std::fs::remove_file(&missing).ok();
+    let (cache, _) = cache("watched", &Config::default());
+    let policy: CachePolicy = policy(vec![watched.clone(), missing.clone()], &[]);
+
+    cache.insert("packages", &policy, &text("pacman"));
+    assert_eq!(cache.get("packages", &policy), Some(text("pacman")));
+    File::options()
+        .write(true)
+        .open(&watched)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+    assert_eq!(cache.get("packages", &policy), None);
+
+    cache.insert("packages", &policy, &text("pacman"));
+    std::fs::write(&missing, "").unwrap();
+    assert_eq!(
+        cache.get("packages", &policy),
+        None,
+        "a watched file has been created"
+    );
+    std::fs::remove_file(&missing).ok();
+}
+
+#[test]
+fn values_expire_when_a_watched_variable_changes() {
+    let (cache, _) = cache("env", &Config::default());
+    let policy: CachePolicy = policy(Vec::new(), &["AFETCH_CACHE_TEST_DESKTOP"]);
+
+    cache.insert("desktop", &policy, &text("KDE"));
+    assert_eq!(cache.get("desktop", &policy), Some(text("KDE")));
+    std::env::set_var("AFETCH_CACHE_TEST_DESKTOP", "GNOME");
+    assert_eq!(cache.get("desktop", &policy), None);
+    std::env::remove_var("AFETCH_CACHE_TEST_DESKTOP");
+    assert_eq!(cache.get("desktop", &policy), Some(text("KDE")));
+}
+
+#[test]
+fn disabling_entries_discards_the_cache() {
+    let config: Config = Config::default();
+    let (cache, path) = cache("config", &config);
+    let policy: CachePolicy = policy(Vec::new(), &[]);
+    cache.insert("desktop", &policy, &text("KDE"));
+    cache.save();
+
+    let disabled: Config = Config {
+        disabled_entries: vec![EntryId::DesktopVersion],
+        ..Config::default()
+    };
+    let cache: Cache = Cache::load_from(Some(path.clone()), Path::new("/"), &disabled, false);
+    assert_eq!(cache.get("desktop", &policy), None);
+    let cache: Cache = Cache::load_from(Some(path), Path::new("/"), &config, false);
+    assert_eq!(cache.get("desktop", &policy), Some(text("KDE")));
+}
+
+#[test]
+fn values_read_from_another_sysroot_are_discarded() {
+    let config: Config = Config::default();
+    let (cache, path) = cache("sysroot", &config);
+    let policy: CachePolicy = policy(Vec::new(), &[]);
+    cache.insert("gpu", &policy, &text("NVIDIA"));
+    cache.save();
+
+    let cache: Cache = Cache::load_from(Some(path.clone()), Path::new("/mnt"), &config, false);
+    assert_eq!(cache.get("gpu", &policy), None);
+    let cache: Cache = Cache::load_from(Some(path), Path::new("/"), &config, false);
+    assert_eq!(cache.get("gpu", &policy), Some(text("NVIDIA")));
+}
+
+#[test]
+fn watched_paths_are_resolved_in_the_sysroot() {
+    let config: Config = Config::default();
+    let registry: Registry = Registry::builtin();
+    let vars: HashMap<String, String> =
+        HashMap::from([("HOME".to_owned(), "/home/user".to_owned())]);
+    let environment: Environment = Environment::new("/mnt", vars);
+    let watched = |id: &str| {
+        registry
+            .cache_policy(registry.get(id).unwrap(), &config, &environment)
+            .unwrap()
+            .watched
+    };
+
+    let packages: Vec<PathBuf> = watched("packages");
+    assert!(packages.contains(&PathBuf::from("/mnt/var/lib/pacman/local")));
+    assert!(packages.contains(&PathBuf::from("/mnt/home/user/.local/share/flatpak/app")));
+    assert_eq!(
+        watched("gpu"),
+        vec![
+            PathBuf::from("/mnt/sys/class/drm"),
+            PathBuf::from("/mnt/proc/driver/nvidia/version")
+        ]
+    );
+}
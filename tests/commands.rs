@@ -0,0 +1,213 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use afetch::cache::Cache;
+use afetch::config::Config;
+use afetch::entries::Registry;
+use afetch::system::command::ReplayRunner;
+use afetch::system::infos::Infos;
+use afetch::system::report::{FetchReport, PackageManagerInfo};
+use afetch::utils::return_str_from_command_with_timeout;
+use common::FixtureSystem;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+fn package_manager(name: &str, count: usize) -> PackageManagerInfo {
+    PackageManagerInfo {
+        name: name.to_owned(),
+        count,
+    }
+}
+
+#[tokio::test]
+async fn pacman_and_flatpak_packages() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("arch")
+        .infos()
+        .await;
+    assert_eq!(
+        infos.get_packages().await,
+        vec![package_manager("pacman", 6), package_manager("flatpak", 2)]
+    );
+}
+
+#[tokio::test]
+async fn dpkg_and_snap_packages() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("debian")
+        .infos()
+        .await;
+    assert_eq!(
+        infos.get_packages().await,
+        vec![package_manager("dpkg", 4), package_manager("snap", 2)]
+    );
+}
+
+#[tokio::test]
+async fn no_package_manager() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_runner(ReplayRunner::new())
+        .infos()
+        .await;
+    assert!(infos.get_packages().await.is_empty());
+}
+
+#[tokio::test]
+async fn fish_version() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("arch")
+        .with_vars(&[("SHELL", "/usr/bin/fish")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_shell(), "fish 3.7.1");
+}
+
+#[tokio::test]
+async fn shell_without_version_output() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_runner(ReplayRunner::new())
+        .with_vars(&[("SHELL", "/usr/bin/fish")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_shell(), "fish");
+}
+
+#[tokio::test]
+async fn kwin_version_from_qdbus() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("kde")
+        .with_vars(&[("XDG_CURRENT_DESKTOP", "KDE")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_de(), ("KDE".to_owned(), "5.27.10".to_owned()));
+}
+
+#[tokio::test]
+async fn plasma_version_without_qdbus() {
+    let runner: ReplayRunner =
+        ReplayRunner::new().with_output("plasmashell --version", "plasmashell 6.0.4\n");
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_runner(runner)
+        .with_vars(&[("XDG_CURRENT_DESKTOP", "KDE")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_de(), ("KDE".to_owned(), "6.0.4".to_owned()));
+}
+
+#[tokio::test]
+async fn intel_and_nvidia_gpus() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("arch")
+        .infos()
+        .await;
+    assert_eq!(
+        infos.get_gpus(),
+        vec![
+            "Intel Alder Lake-P Integrated Graphics Controller".to_owned(),
+            "NVIDIA GeForce RTX 3050 Ti Mobile".to_owned()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn amd_gpu() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_commands("debian")
+        .infos()
+        .await;
+    assert_eq!(
+        infos.get_gpus(),
+        vec!["AMD ATI Radeon RX 6600/6600 XT/6600M".to_owned()]
+    );
+}
+
+#[tokio::test]
+async fn no_gpu_without_lspci() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_runner(ReplayRunner::new())
+        .infos()
+        .await;
+    assert!(infos.get_gpus().is_empty());
+}
+
+#[cfg(unix)]
+fn shell(command: &str, timeout: Duration) -> (String, Duration) {
+    let started: Instant = Instant::now();
+    let output: String =
+        return_str_from_command_with_timeout(Command::new("sh").arg("-c").arg(command), timeout);
+    (output, started.elapsed())
+}
+
+#[cfg(unix)]
+#[test]
+fn commands_are_killed_after_their_timeout() {
+    let (output, elapsed) = shell("sleep 5; echo late", Duration::from_millis(200));
+    assert_eq!(output, "");
+    assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+}
+
+#[cfg(unix)]
+#[test]
+fn background_processes_do_not_hold_the_output() {
+    let (output, elapsed) = shell("sleep 5 & echo up", Duration::from_secs(3));
+    assert_eq!(output, "up\n");
+    assert!(elapsed < Duration::from_secs(2), "{:?}", elapsed);
+}
+
+/// Whether a live process is left in the process group `pgid`.
+fn process_group_is_running(pgid: &str) -> bool {
+    std::fs::read_dir("/proc")
+        .unwrap()
+        .flatten()
+        .any(|process| {
+            let stat: String =
+                std::fs::read_to_string(process.path().join("stat")).unwrap_or_default();
+            // The fields after the parenthesized command name are the state, the ppid and the pgid.
+            let fields: Vec<&str> = stat
+                .rsplit_once(')')
+                .map(|(_, fields)| fields.split_whitespace().collect())
+                .unwrap_or_default();
+            fields.len() > 2 && fields[0] != "Z" && fields[2] == pgid
+        })
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn entry_commands_are_killed_at_the_entry_deadline() {
+    let pid_file: PathBuf = std::env::temp_dir().join("afetch-slow-entry.pid");
+    std::fs::remove_file(&pid_file).ok();
+    let config: Config = serde_yaml::from_str(&format!(
+        "custom:\n  - label: Slow\n    command: echo $$ > {}; sleep 37; echo late\nentries: [slow]\ntimeout:\n  entries:\n    slow: 300\n",
+        pid_file.display()
+    ))
+    .unwrap();
+    let registry: Registry = Registry::from_config(&config);
+    let config: Arc<Config> = Arc::new(config);
+    let infos: Arc<Infos> = Arc::new(Infos::init(None, Arc::clone(&config)).await);
+
+    let started: Instant = Instant::now();
+    let report: FetchReport = registry.collect(infos, config, &Cache::disabled()).await;
+    assert!(report.entries.is_empty());
+    assert!(
+        started.elapsed() < Duration::from_secs(2),
+        "{:?}",
+        started.elapsed()
+    );
+
+    // The shell leads the process group of the command, `sleep` included.
+    let pgid: String = std::fs::read_to_string(&pid_file)
+        .unwrap()
+        .trim()
+        .to_owned();
+    let killed: Instant = Instant::now();
+    while process_group_is_running(&pgid) {
+        assert!(
+            killed.elapsed() < Duration::from_secs(1),
+            "{} is running",
+            pgid
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,87 @@
+//! The fixture systems of `tests/fixtures` the system information is read from.
+#![allow(dead_code)]
+
+use afetch::config::Config;
+use afetch::system::command::ReplayRunner;
+use afetch::system::environment::Environment;
+use afetch::system::infos::Infos;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// A fixture tree of `tests/fixtures` along with the environment variables and the outputs of
+/// the external programs of the system.
+pub struct FixtureSystem {
+    sysroot: PathBuf,
+    vars: HashMap<String, String>,
+    pid: Option<u32>,
+    runner: Option<ReplayRunner>,
+}
+
+impl FixtureSystem {
+    pub fn new(fixture: &str) -> Self {
+        Self {
+            sysroot: fixtures().join(fixture),
+            vars: HashMap::new(),
+            pid: None,
+            runner: None,
+        }
+    }
+
+    pub fn with_vars(mut self, vars: &[(&str, &str)]) -> Self {
+        self.vars.extend(
+            vars.iter()
+                .map(|(name, value)| ((*name).to_owned(), (*value).to_owned())),
+        );
+        self
+    }
+
+    /// The process whose parents are read from the `proc` folder of the fixture.
+    pub fn with_pid(mut self, pid: u32) -> Self {
+        self.pid = Some(pid);
+        self
+    }
+
+    pub fn with_runner(mut self, runner: ReplayRunner) -> Self {
+        self.runner = Some(runner);
+        self
+    }
+
+    /// Serves the outputs recorded in `tests/fixtures/commands/<fixture>.yaml`.
+    pub fn with_commands(self, fixture: &str) -> Self {
+        let runner: ReplayRunner = ReplayRunner::from_file(
+            fixtures()
+                .join("commands")
+                .join(format!("{}.yaml", fixture)),
+        )
+        .unwrap();
+        self.with_runner(runner)
+    }
+
+    pub fn environment(&self) -> Environment {
+        let environment: Environment = Environment::new(&self.sysroot, self.vars.clone());
+        match self.pid {
+            Some(pid) => environment.with_pid(pid),
+            None => environment,
+        }
+    }
+
+    /// The system information of the fixture. Without a runner, external programs are looked
+    /// for in the `PATH` of the fixture, so none is found unless it is set.
+    pub async fn infos(self) -> Infos {
+        let config: Config = serde_yaml::from_str("disabled_entries: [cpu, memory]").unwrap();
+        let infos: Infos = Infos::init(None, Arc::new(config))
+            .await
+            .with_environment(self.environment());
+        match self.runner {
+            Some(runner) => infos.with_runner(Arc::new(runner)),
+            None => infos,
+        }
+    }
+}
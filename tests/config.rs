@@ -0,0 +1,349 @@
+use afetch::cli::{Cli, CliError, Command};
+use afetch::config::check::{parse_file, validate, Diagnostic};
+use afetch::config::layers;
+use afetch::config::{Config, EntryId, Language, LayoutItem, LogoConfig, LogoRenderer, LogoStatus};
+use afetch::entries::Registry;
+use serde_yaml::Value;
+use std::path::{Path, PathBuf};
+
+fn parse(args: &[&str]) -> Result<Cli, CliError> {
+    Cli::parse(args.iter().map(|arg| (*arg).to_owned()))
+}
+
+#[test]
+fn flag_values_inline_or_separate() {
+    let separate: Cli =
+        parse(&["--logo", "Arch", "-c", "afetch.yaml", "--only", "os, cpu"]).unwrap();
+    let inline: Cli = parse(&["--logo=Arch", "--config=afetch.yaml", "--only=os,cpu"]).unwrap();
+    assert_eq!(separate, inline);
+    assert_eq!(inline.logo.as_deref(), Some("arch"));
+    assert_eq!(inline.config, Some(PathBuf::from("afetch.yaml")));
+    assert_eq!(inline.only, Some(vec![EntryId::Os, EntryId::Cpu]));
+    // `=` only splits long flags, the value of a short one can contain it.
+    assert_eq!(
+        parse(&["-c", "a=b.yaml"]).unwrap().config,
+        Some(PathBuf::from("a=b.yaml"))
+    );
+
+    assert_eq!(
+        parse(&["--logo"]),
+        Err(CliError::MissingValue("--logo".to_owned()))
+    );
+    assert_eq!(
+        parse(&["--json=yes"]),
+        Err(CliError::UnexpectedValue("--json".to_owned()))
+    );
+    assert_eq!(
+        parse(&["--jsno"]),
+        Err(CliError::UnknownFlag("--jsno".to_owned()))
+    );
+    assert_eq!(
+        parse(&["fetch"]),
+        Err(CliError::UnexpectedArgument("fetch".to_owned()))
+    );
+}
+
+#[test]
+fn commands() {
+    let command = |args: &[&str]| parse(args).map(|cli| cli.command);
+    assert_eq!(command(&[]), Ok(None));
+    assert_eq!(
+        command(&["config", "check"]),
+        Ok(Some(Command::ConfigCheck))
+    );
+    assert_eq!(
+        command(&["lang", "missing", "de"]),
+        Ok(Some(Command::LangMissing(Some("de".to_owned()))))
+    );
+    assert_eq!(command(&["logos", "list"]), Ok(Some(Command::LogosList)));
+    assert_eq!(
+        command(&["logos", "show", "Ubuntu"]),
+        Ok(Some(Command::LogosShow(Some("ubuntu".to_owned()))))
+    );
+    assert_eq!(
+        command(&["--all", "logos", "show"]),
+        Ok(Some(Command::LogosShow(None)))
+    );
+
+    assert_eq!(
+        command(&["config", "fix"]),
+        Err(CliError::UnknownCommand("config fix".to_owned()))
+    );
+    assert_eq!(
+        command(&["logos"]),
+        Err(CliError::UnknownCommand("logos".to_owned()))
+    );
+    assert_eq!(
+        command(&["fetch"]),
+        Err(CliError::UnexpectedArgument("fetch".to_owned()))
+    );
+}
+
+#[test]
+fn all_is_only_accepted_by_logos_show() {
+    let unexpected: Result<Cli, CliError> = Err(CliError::UnexpectedArgument("--all".to_owned()));
+    assert_eq!(parse(&["--all"]), unexpected);
+    assert_eq!(parse(&["logos", "list", "--all"]), unexpected);
+    assert_eq!(parse(&["logos", "show", "ubuntu", "--all"]), unexpected);
+    assert_eq!(
+        parse(&["logos", "show"]),
+        Err(CliError::MissingValue("logos show".to_owned()))
+    );
+}
+
+#[test]
+fn only_filters_the_layout() {
+    let mut config: Config = Config::default();
+    let registry: Registry = Registry::from_config(&config);
+    parse(&["--only", "cpu,os", "--no-logo"])
+        .unwrap()
+        .apply(&mut config, &registry);
+    // The entries keep the order of the layout, along with the other lines.
+    assert_eq!(
+        config.entries.unwrap(),
+        vec![
+            LayoutItem::Title,
+            LayoutItem::Separator,
+            LayoutItem::Entry(EntryId::Os),
+            LayoutItem::Entry(EntryId::Cpu),
+            LayoutItem::Spacer,
+            LayoutItem::Spacer,
+            LayoutItem::ColorBlocks,
+        ]
+    );
+    assert_eq!(config.logo.status, LogoStatus::Disable);
+}
+
+#[test]
+fn disable_removes_entries_from_the_layout() {
+    let mut config: Config =
+        serde_yaml::from_str("entries: [title, os, kernel, cpu]\ndisabled_entries: [gpu]\n")
+            .unwrap();
+    let registry: Registry = Registry::from_config(&config);
+    parse(&["--disable", "kernel", "--disable=cpu,gpu"])
+        .unwrap()
+        .apply(&mut config, &registry);
+    assert_eq!(
+        config.entries.unwrap(),
+        vec![LayoutItem::Title, LayoutItem::Entry(EntryId::Os)]
+    );
+    assert_eq!(
+        config.disabled_entries,
+        vec![EntryId::Gpu, EntryId::Kernel, EntryId::Cpu]
+    );
+
+    let mut config: Config = Config::default();
+    parse(&["--disable", "gpu"])
+        .unwrap()
+        .apply(&mut config, &registry);
+    assert!(!config.is_entry_enabled("gpu"));
+    assert!(config.is_entry_enabled("cpu"));
+}
+
+#[test]
+fn unknown_names_on_the_command_line() {
+    let registry: Registry = Registry::builtin();
+    let diagnostics: Vec<String> = parse(&["--only", "cpu,mem", "--disable", "cpu-usage,gpus"])
+        .unwrap()
+        .validate(&registry, &[])
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect();
+    assert_eq!(
+        diagnostics,
+        vec![
+            "`--disable`: there is no entry named `gpus`, did you mean `gpu`?",
+            "`--only`: there is no entry named `mem`",
+        ]
+    );
+}
+
+fn parse_errors(yaml: &str) -> Vec<String> {
+    parse_file(Path::new("afetch.yaml"), yaml)
+        .1
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect()
+}
+
+#[test]
+fn unknown_keys_are_reported() {
+    assert_eq!(
+        parse_errors("languag: fr\nlogo:\n  size: small\n  colour: [1, 2, 3]\n"),
+        vec![
+            "afetch.yaml, line 1, column 10: `languag`: there is no key named `languag`, did you mean `language`?",
+            "afetch.yaml, line 4, column 11: `logo.colour`: there is no key named `colour`, did you mean `colors`?",
+        ]
+    );
+    assert!(parse_errors("language: fr\nlogo:\n  size: small\n").is_empty());
+}
+
+#[test]
+fn every_invalid_value_is_reported() {
+    assert_eq!(
+        parse_errors("language: xx\nlogo: {size: huge}\ntext_color: [1, 2, 300]\n"),
+        vec![
+            "afetch.yaml, line 1, column 11: `language`: expected `auto` or one of `en`, `fr`, found `xx`",
+            "afetch.yaml, line 2, column 7: `logo.size`: expected one of `auto`, `small`, `large`, found `huge`",
+            "afetch.yaml, line 3, column 13: `text_color[2]`: invalid value: integer `300`, expected u8",
+        ]
+    );
+    assert_eq!(
+        parse_errors("[1, 2]"),
+        vec!["afetch.yaml, line 1, column 1: expected a mapping of configuration keys"]
+    );
+}
+
+#[test]
+fn every_profile_is_checked() {
+    let path: PathBuf = PathBuf::from("tests/fixtures/config/invalid.yaml");
+    let diagnostics: Vec<String> = layers::check(Some(&path), None)
+        .err()
+        .unwrap()
+        .iter()
+        .map(|diagnostic| {
+            diagnostic
+                .to_string()
+                .replace(&path.display().to_string(), "invalid.yaml")
+        })
+        .collect();
+    assert_eq!(
+        diagnostics,
+        vec![
+            "invalid.yaml, line 1, column 13: `text_color[2]`: invalid value: integer `300`, expected u8",
+            "invalid.yaml, line 2, column 19: `disabled_entires`: there is no key named `disabled_entires`, did you mean `disabled_entries`?",
+            "invalid.yaml, line 4, column 5: `disabled_entries[0]`: there is no entry named `batery`, did you mean `battery`?",
+            "invalid.yaml, line 6, column 10: `logo.staus`: there is no key named `staus`, did you mean `status`?",
+            "invalid.yaml, line 7, column 13: `logo.position`: expected one of `left`, `right`, `top`, `bottom`, `below`, found `middle`",
+            "invalid.yaml, line 11, column 14: `custom[0].timeout`: there is no key named `timeout`",
+            "invalid.yaml, line 14, column 15: `profiles.work.language`: expected `auto` or one of `en`, `fr`, found `xx`",
+            "invalid.yaml, line 15, column 23: `profiles.work.disabled_entries[0]`: there is no entry named `netwrk`, did you mean `network`?",
+            "invalid.yaml, line 18, column 13: `profiles.home.logo.size`: expected one of `auto`, `small`, `large`, found `huge`",
+        ]
+    );
+}
+
+#[test]
+fn keywords_accept_their_legacy_spellings() {
+    let logo: LogoConfig = serde_yaml::from_str("status: Disabled\nchar_type: image\n").unwrap();
+    assert_eq!(logo.status, LogoStatus::Disable);
+    assert_eq!(logo.char_type, LogoRenderer::Picture);
+    assert_eq!(" enabled ".parse(), Ok(LogoStatus::Enable));
+    assert_eq!("FR".parse(), Ok(Language::Code("fr".to_owned())));
+
+    // The first spelling is the one written back.
+    let yaml: String = serde_yaml::to_string(&logo).unwrap();
+    assert!(yaml.contains("status: disable\n"));
+    assert!(yaml.contains("char_type: picture\n"));
+}
+
+#[test]
+fn invalid_keywords_are_reported_with_a_suggestion() {
+    assert_eq!(
+        "disabel".parse::<LogoStatus>(),
+        Err(
+            "expected one of `enable`, `enabled`, `disable`, `disabled`, found `disabel`, did you mean `disable`?"
+                .to_owned()
+        )
+    );
+    assert_eq!(
+        "ascii".parse::<LogoRenderer>(),
+        Err(
+            "expected one of `braille`, `picture`, `image`, `picture-braille`, `picture-blocks`, found `ascii`"
+                .to_owned()
+        )
+    );
+}
+
+fn yaml(source: &str) -> Value {
+    serde_yaml::from_str(source).unwrap()
+}
+
+#[test]
+fn layers_are_merged_key_by_key() {
+    let mut base: Value = yaml(
+        "language: fr\nlogo:\n  status: disable\n  size: small\ndisabled_entries: [gpu, cpu]\n",
+    );
+    layers::merge(
+        &mut base,
+        yaml("logo:\n  size: large\ndisabled_entries: [battery]\ntext_color: [1, 2, 3]\n"),
+    );
+    assert_eq!(
+        base,
+        yaml(
+            "language: fr\nlogo:\n  status: disable\n  size: large\ndisabled_entries: [battery]\ntext_color: [1, 2, 3]\n"
+        )
+    );
+}
+
+#[test]
+fn environment_variables_build_a_layer() {
+    let vars = [
+        ("AFETCH_LANGUAGE", "fr"),
+        ("AFETCH_LOGO__STATUS", "disable"),
+        ("AFETCH_LOGO__CHAR_TYPE", "image"),
+        ("AFETCH_DISABLED_ENTRIES", "battery, network,"),
+        ("AFETCH_TEXT_COLOR", "1,2,3"),
+        ("AFETCH_UNKNOWN", "value"),
+        ("HOME", "/home/user"),
+    ];
+    let layer: Value = layers::env_layer(
+        vars.iter()
+            .map(|(name, value)| ((*name).to_owned(), (*value).to_owned())),
+    );
+    assert_eq!(
+        layer,
+        yaml(
+            "language: fr\nlogo:\n  status: disable\n  char_type: image\ndisabled_entries: [battery, network]\ntext_color: [1, 2, 3]\n"
+        )
+    );
+}
+
+#[test]
+fn profiles_are_applied_over_the_files() {
+    let path: PathBuf = PathBuf::from("tests/fixtures/config/profiles.yaml");
+    let base: Config = layers::load(Some(&path), None).ok().unwrap().config;
+    assert_eq!(base.language, Language::Code("fr".to_owned()));
+    assert_eq!(base.logo.status, LogoStatus::Disable);
+    assert_eq!(base.disabled_entries, vec![EntryId::Battery, EntryId::Gpu]);
+
+    let work: Config = layers::load(Some(&path), Some("work")).ok().unwrap().config;
+    assert_eq!(work.language, Language::Code("fr".to_owned()));
+    assert_eq!(work.logo.status, LogoStatus::Enable);
+    assert_eq!(work.logo.picture_path, "logo.png");
+    assert_eq!(work.disabled_entries, vec![EntryId::Network]);
+
+    let diagnostics: Vec<String> = layers::load(Some(&path), Some("wor"))
+        .err()
+        .unwrap()
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect();
+    assert_eq!(
+        diagnostics,
+        vec!["`--profile`: there is no profile named `wor`, did you mean `work`?"]
+    );
+}
+
+fn diagnostics(yaml: &str) -> Vec<String> {
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    validate(&config, &Registry::from_config(&config))
+        .iter()
+        .map(Diagnostic::to_string)
+        .collect()
+}
+
+#[test]
+fn custom_entries_cannot_replace_builtin_ones() {
+    assert_eq!(
+        diagnostics("custom:\n  - label: Hostname\n    id: os\n    command: hostname\n"),
+        vec!["`custom[0]`: the builtin entry `os` has the same id, set another `id`"]
+    );
+    assert_eq!(
+        diagnostics(
+            "custom:\n  - label: VPN\n    command: vpn\n  - label: vpn\n    command: vpn\n"
+        ),
+        vec!["`custom[1]`: another custom entry is already named `vpn`"]
+    );
+    assert!(diagnostics("custom:\n  - label: Hostname\n    command: hostname\n").is_empty());
+}
@@ -0,0 +1,220 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use afetch::system::environment::Environment;
+use afetch::system::infos::Infos;
+use afetch::system::pid::{get_parent_pid_names, get_ppid};
+use common::FixtureSystem;
+use std::path::PathBuf;
+
+#[tokio::test]
+async fn distribution_from_etc_os_release() {
+    let infos: Infos = FixtureSystem::new("arch").infos().await;
+    assert_eq!(infos.get_linux_distribution(), "Arch Linux");
+    assert_eq!(infos.get_os_logo().unwrap().unwrap().name, "archlinux");
+}
+
+#[tokio::test]
+async fn distribution_from_usr_lib_os_release() {
+    let infos: Infos = FixtureSystem::new("ubuntu").infos().await;
+    assert_eq!(infos.get_linux_distribution(), "Ubuntu");
+}
+
+#[tokio::test]
+async fn ubuntu_flavour_from_xdg_config_dirs() {
+    let infos: Infos = FixtureSystem::new("ubuntu")
+        .with_vars(&[("XDG_CONFIG_DIRS", "/etc/xdg/xdg-ubuntu-mate:/etc/xdg")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_linux_distribution(), "Ubuntu Mate");
+}
+
+#[tokio::test]
+async fn distribution_from_release_file() {
+    let infos: Infos = FixtureSystem::new("raspberry").infos().await;
+    assert_eq!(infos.get_linux_distribution(), "Armbian");
+}
+
+#[tokio::test]
+async fn host_from_dmi_product() {
+    let infos: Infos = FixtureSystem::new("arch").infos().await;
+    assert_eq!(infos.get_host(), "XPS 15 9520 1.0.2");
+}
+
+#[tokio::test]
+async fn host_falls_back_to_dmi_board() {
+    let infos: Infos = FixtureSystem::new("ubuntu").infos().await;
+    assert_eq!(infos.get_host(), "ASUSTeK COMPUTER INC. PRIME B450M-A");
+}
+
+#[tokio::test]
+async fn host_from_device_tree() {
+    let infos: Infos = FixtureSystem::new("raspberry").infos().await;
+    assert_eq!(infos.get_host(), "Raspberry Pi 4 Model B Rev 1.4");
+}
+
+#[tokio::test]
+async fn resolution_from_drm_modes() {
+    let infos: Infos = FixtureSystem::new("ubuntu").infos().await;
+    assert_eq!(infos.get_screens_resolution(), "1920x1080");
+}
+
+#[tokio::test]
+async fn resolution_ignores_x11_tools_outside_the_path() {
+    // `DISPLAY` alone is not enough, the X11 tools are not found without a `PATH`.
+    let infos: Infos = FixtureSystem::new("ubuntu")
+        .with_vars(&[("DISPLAY", ":0")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_screens_resolution(), "1920x1080");
+}
+
+#[tokio::test]
+async fn shell_from_environment() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("SHELL", "/bin/zsh"), ("SHELL_VERSION", "5.9")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_shell(), "zsh 5.9");
+}
+
+#[tokio::test]
+async fn bash_version_from_environment() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[
+            ("SHELL", "/usr/bin/bash"),
+            ("BASH_VERSION", "5.2.26(1)-release"),
+        ])
+        .infos()
+        .await;
+    assert_eq!(infos.get_shell(), "bash 5.2.26(1)-release");
+}
+
+#[tokio::test]
+async fn os_name_from_os_release() {
+    assert_eq!(
+        FixtureSystem::new("arch").infos().await.get_os_name(),
+        "Arch Linux"
+    );
+    assert_eq!(
+        FixtureSystem::new("ubuntu").infos().await.get_os_name(),
+        "Ubuntu"
+    );
+    assert_eq!(
+        FixtureSystem::new("raspberry").infos().await.get_os_name(),
+        ""
+    );
+}
+
+#[tokio::test]
+async fn terminal_from_parent_processes() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("SHELL", "/bin/zsh")])
+        .with_pid(4242)
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal(), "Alacritty");
+}
+
+#[tokio::test]
+async fn terminal_from_term_program() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("TERM_PROGRAM", "vscode")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal(), "VSCode");
+}
+
+#[tokio::test]
+async fn alacritty_font_from_home_config() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("TERM", "alacritty"), ("HOME", "/home/user")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal_font(), "JetBrains Mono");
+}
+
+#[tokio::test]
+async fn alacritty_font_from_xdg_config_home() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[
+            ("TERM", "alacritty"),
+            ("HOME", "/nonexistent"),
+            ("XDG_CONFIG_HOME", "/home/user/.config"),
+        ])
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal_font(), "JetBrains Mono");
+}
+
+#[tokio::test]
+async fn deepin_terminal_font() {
+    let infos: Infos = FixtureSystem::new("ubuntu")
+        .with_vars(&[("TERM", "deepin-terminal"), ("HOME", "/home/user")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal_font(), "Noto Sans Mono");
+}
+
+#[tokio::test]
+async fn missing_font_config() {
+    let infos: Infos = FixtureSystem::new("ubuntu")
+        .with_vars(&[("TERM", "alacritty"), ("HOME", "/home/user")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_terminal_font(), "");
+}
+
+#[tokio::test]
+async fn desktop_from_xdg_current_desktop() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("XDG_CURRENT_DESKTOP", "X-Cinnamon")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_de().0, "Cinnamon");
+}
+
+#[tokio::test]
+async fn deepin_version_from_os_version() {
+    let infos: Infos = FixtureSystem::new("deepin")
+        .with_vars(&[("XDG_CURRENT_DESKTOP", "Deepin")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_de(), ("Deepin".to_owned(), "20".to_owned()));
+}
+
+#[tokio::test]
+async fn desktop_from_session_id() {
+    let infos: Infos = FixtureSystem::new("arch")
+        .with_vars(&[("MATE_DESKTOP_SESSION_ID", "this-is-deprecated")])
+        .infos()
+        .await;
+    assert_eq!(infos.get_de().0, "Mate");
+}
+
+#[test]
+fn parent_pid_from_proc() {
+    let environment: Environment = FixtureSystem::new("arch").environment();
+    assert_eq!(get_ppid(&environment, "4242"), Some("1234".to_owned()));
+    assert_eq!(get_ppid(&environment, "1"), None);
+    assert_eq!(
+        get_parent_pid_names(&environment.with_pid(4242)),
+        vec!["zsh", "alacritty"]
+    );
+}
+
+#[test]
+fn paths_are_resolved_in_the_sysroot() {
+    let environment: Environment = FixtureSystem::new("arch").environment();
+    assert!(environment.exists("/etc/os-release"));
+    assert!(!environment.exists("/etc/lsb-release"));
+    assert_eq!(
+        environment.path("/etc/os-release"),
+        environment.sysroot.join("etc").join("os-release")
+    );
+    assert_eq!(
+        environment.path("relative/path"),
+        PathBuf::from("relative/path")
+    );
+}
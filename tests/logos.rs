@@ -0,0 +1,445 @@
+use afetch::config::{Config, LogoConfig, LogoPosition, LogoSize};
+use afetch::logos::ascii::to_ascii;
+use afetch::logos::catalog::{list_logos_in, preview, LogoEntry};
+use afetch::logos::custom::{logo_dirs, parse_logo};
+use afetch::logos::layout::{arrange, logo_origin};
+use afetch::logos::{builtin_logo, get_logo_in, logo_names, Logo, LogoColor, LOGOS};
+use afetch::system::environment::Environment;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+fn logo_config(size: LogoSize, position: LogoPosition) -> LogoConfig {
+    LogoConfig {
+        size,
+        position,
+        ..LogoConfig::default()
+    }
+}
+
+fn dirs() -> Vec<PathBuf> {
+    let logos: PathBuf = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("logos");
+    vec![logos.join("user"), logos.join("system")]
+}
+
+#[test]
+fn user_file_overrides_builtin_logo() {
+    let logo: Logo = get_logo_in("Arch Linux", &dirs()).unwrap().unwrap();
+    assert_eq!(logo.color(), LogoColor::Rgb(0x17, 0x93, 0xd1));
+    assert_eq!(logo.art.lines().count(), 4);
+    assert!(logo.path.as_ref().unwrap().ends_with("user/archlinux.txt"));
+}
+
+#[test]
+fn system_file_is_found_through_an_alias() {
+    let logo: Logo = get_logo_in("manjarolinux", &dirs()).unwrap().unwrap();
+    assert!(logo.path.as_ref().unwrap().ends_with("system/manjaro.txt"));
+    assert_eq!(logo.color(), LogoColor::Ansi(6));
+}
+
+#[test]
+fn file_names_are_normalized() {
+    let logo: Logo = get_logo_in("my distro", &dirs()).unwrap().unwrap();
+    assert_eq!(logo.name, "mydistro");
+    assert_eq!(logo.color(), LogoColor::Ansi(208));
+}
+
+#[test]
+fn builtin_logo_without_file() {
+    let logo: Logo = get_logo_in("debian", &dirs()).unwrap().unwrap();
+    assert_eq!(logo, builtin_logo("debian").unwrap());
+    assert_eq!(logo.color(), LogoColor::Ansi(161));
+    assert!(logo.path.is_none());
+}
+
+#[test]
+fn unknown_logo() {
+    assert_eq!(get_logo_in("templeos", &dirs()), Ok(None));
+    assert_eq!(get_logo_in("readme", &dirs()), Ok(None));
+}
+
+#[test]
+fn invalid_logo_file() {
+    let error: String = get_logo_in("broken", &dirs()).unwrap_err();
+    assert!(error.contains("broken.txt"), "{}", error);
+    assert!(error.contains("unknown key `colour`"), "{}", error);
+}
+
+#[test]
+fn plain_art_is_colored_with_the_accent() {
+    let logo: Logo = parse_logo("test", "---\ncolor: 1, 2, 3\n---\nab\ncd\n").unwrap();
+    assert_eq!(
+        logo.lines(),
+        vec!["\x1b[38;2;1;2;3mab\x1b[0m", "\x1b[38;2;1;2;3mcd\x1b[0m"]
+    );
+}
+
+#[test]
+fn escape_codes_carry_over_lines() {
+    let logo: Logo = parse_logo("test", "\x1b[31mab\ncd\x1b[0;1mef\ngh\n").unwrap();
+    assert_eq!(
+        logo.lines(),
+        vec![
+            "\x1b[38;5;6m\x1b[31mab\x1b[0m",
+            "\x1b[31mcd\x1b[0;1mef\x1b[0m",
+            "\x1b[0;1mgh\x1b[0m"
+        ]
+    );
+}
+
+#[test]
+fn neofetch_placeholders() {
+    let logo: Logo = get_logo_in("void", &dirs()).unwrap().unwrap();
+    assert_eq!(logo.colors, vec![LogoColor::Ansi(2), LogoColor::Ansi(8)]);
+    let lines: Vec<String> = logo.lines();
+    assert_eq!(lines[0], "\x1b[38;5;2m\x1b[38;5;2m    _______\x1b[0m");
+    // The second line starts in the color the first one ends with.
+    assert_eq!(lines[1], "\x1b[38;5;2m _ \\______ -\x1b[0m");
+    assert_eq!(
+        lines[2],
+        "\x1b[38;5;2m| \\  \x1b[38;5;8m___\x1b[38;5;2m  \\ |\x1b[0m"
+    );
+    assert_eq!(logo.widths(), vec![11, 12, 13, 13, 13, 13, 10]);
+}
+
+#[test]
+fn unknown_placeholders_are_kept() {
+    let logo: Logo = parse_logo("test", "${c7}$ ${c2}€${x}").unwrap();
+    assert_eq!(
+        logo.lines(),
+        vec!["\x1b[38;5;6m${c7}$ \x1b[38;5;6m€${x}\x1b[0m"]
+    );
+    assert_eq!(logo.widths(), vec![12]);
+}
+
+#[test]
+fn placeholder_colors_are_remapped() {
+    let colors: BTreeMap<String, LogoColor> = BTreeMap::from([
+        ("c2".to_owned(), LogoColor::Rgb(255, 0, 0)),
+        ("c4".to_owned(), LogoColor::Ansi(4)),
+    ]);
+    let logo: Logo = get_logo_in("void", &dirs())
+        .unwrap()
+        .unwrap()
+        .with_colors(&colors);
+    assert_eq!(
+        logo.colors,
+        vec![
+            LogoColor::Ansi(2),
+            LogoColor::Rgb(255, 0, 0),
+            LogoColor::Ansi(2),
+            LogoColor::Ansi(4)
+        ]
+    );
+    assert!(logo.lines()[2].contains("\x1b[38;2;255;0;0m___"));
+}
+
+#[test]
+fn colors_in_the_configuration() {
+    let config: Config =
+        serde_yaml::from_str("logo:\n  colors:\n    c1: 208\n    c2: \"#1793d1\"\n").unwrap();
+    assert_eq!(
+        config.logo.colors,
+        BTreeMap::from([
+            ("c1".to_owned(), LogoColor::Ansi(208)),
+            ("c2".to_owned(), LogoColor::Rgb(0x17, 0x93, 0xd1))
+        ])
+    );
+    assert!(serde_yaml::from_str::<Config>("logo:\n  colors:\n    c1: 256\n").is_err());
+}
+
+#[test]
+fn invalid_headers() {
+    assert!(parse_logo("test", "---\ncolor: 32\n").is_err());
+    assert!(parse_logo("test", "---\ncolor: 256\n---\n").is_err());
+    assert!(parse_logo("test", "---\ncolor: #12345\n---\n").is_err());
+    assert!(parse_logo("test", "---\ncolor\n---\n").is_err());
+}
+
+#[test]
+fn braille_cells_are_redrawn_in_ascii() {
+    // Blank, two top dots, all top dots, two bottom dots, all bottom dots, one dot in each half,
+    // six dots and the full cell.
+    assert_eq!(to_ascii("⠀⠃⠛⡄⣤⠅⠿⣿"), " '\".o:*#");
+}
+
+#[test]
+fn ascii_logo_keeps_colors_and_widths() {
+    let logo: Logo = parse_logo("test", "${c2}⣿⠀x\n\x1b[1m─é")
+        .unwrap()
+        .to_ascii();
+    assert_eq!(logo.art, "${c2}# x\n\x1b[1m-?");
+    assert!(logo.art.is_ascii());
+    assert_eq!(logo.widths(), vec![3, 2]);
+}
+
+#[test]
+fn builtin_logos_have_an_ascii_variant() {
+    for logo in logo_names(&[]).iter().filter_map(|name| builtin_logo(name)) {
+        let name: String = logo.name.clone();
+        let large: Vec<usize> = logo.widths();
+        let redrawn: String = to_ascii(&logo.art);
+        let ascii: Logo = logo.to_ascii();
+        assert!(ascii.art.is_ascii(), "{}", name);
+        assert_ne!(ascii.art, redrawn, "{}", name);
+        assert!(ascii.widths().len() <= large.len(), "{}", name);
+        assert!(
+            ascii.widths().iter().max() <= large.iter().max(),
+            "{}",
+            name
+        );
+        // The ASCII variant is kept when a small logo is asked for.
+        assert_eq!(ascii.clone().to_small(), ascii, "{}", name);
+    }
+}
+
+#[test]
+fn builtin_logos_have_a_small_variant() {
+    for logo in logo_names(&[]).iter().filter_map(|name| builtin_logo(name)) {
+        let large: Vec<usize> = logo.widths();
+        let small: Vec<usize> = logo.clone().to_small().widths();
+        assert!(small.len() * 2 <= large.len() + 1, "{}", logo.name);
+        assert!(
+            small.iter().max().unwrap() * 2 <= large.iter().max().unwrap() + 1,
+            "{}",
+            logo.name
+        );
+    }
+}
+
+#[test]
+fn logo_size_follows_the_terminal() {
+    let logo: Logo = builtin_logo("archlinux").unwrap();
+    let small: Logo = logo.clone().to_small();
+    let infos: Vec<String> = vec!["\x1b[1mOS:\x1b[0m Arch Linux".to_owned(); 10];
+    let fit = |size: LogoSize, terminal: Option<(usize, usize)>| {
+        logo.clone()
+            .fit(&logo_config(size, LogoPosition::Left), terminal, &infos)
+            .map(|logo| logo.art)
+    };
+
+    assert_eq!(fit(LogoSize::Auto, Some((120, 40))), Some(logo.art.clone()));
+    // Too narrow, then too short, for the large logo.
+    assert_eq!(fit(LogoSize::Auto, Some((60, 40))), Some(small.art.clone()));
+    assert_eq!(
+        fit(LogoSize::Auto, Some((120, 15))),
+        Some(small.art.clone())
+    );
+    assert_eq!(fit(LogoSize::Auto, Some((30, 40))), None);
+    assert_eq!(fit(LogoSize::Auto, None), Some(logo.art.clone()));
+    assert_eq!(fit(LogoSize::Large, Some((30, 10))), Some(logo.art.clone()));
+    assert_eq!(fit(LogoSize::Small, None), Some(small.art));
+}
+
+#[test]
+fn logo_files_have_no_small_variant() {
+    let logo: Logo = parse_logo("test", &"x".repeat(30)).unwrap();
+    assert_eq!(logo.clone().to_small(), logo);
+    let infos: Vec<String> = vec!["OS: Arch Linux".to_owned()];
+    let config: LogoConfig = LogoConfig::default();
+    assert!(logo.clone().fit(&config, Some((50, 24)), &infos).is_some());
+    assert!(logo.fit(&config, Some((49, 24)), &infos).is_none());
+}
+
+#[test]
+fn logo_size_in_the_configuration() {
+    let config: Config = serde_yaml::from_str("logo:\n  size: Small\n").unwrap();
+    assert_eq!(config.logo.size, LogoSize::Small);
+    assert_eq!(Config::default().logo.size, LogoSize::Auto);
+    assert!(serde_yaml::from_str::<Config>("logo:\n  size: tiny\n").is_err());
+}
+
+fn infos() -> Vec<String> {
+    vec![
+        "\x1b[1mOS:\x1b[0m Void".to_owned(),
+        "Kernel: 6.8".to_owned(),
+        "Shell: fish".to_owned(),
+    ]
+}
+
+#[test]
+fn logo_on_the_left() {
+    let logo: Logo = parse_logo("test", "ab\ncdef").unwrap();
+    let lines: Vec<String> = arrange(&logo, &infos(), LogoPosition::Left, 3);
+    assert_eq!(
+        lines,
+        vec![
+            "   \x1b[38;5;6mab\x1b[0m     \x1b[1mOS:\x1b[0m Void",
+            "   \x1b[38;5;6mcdef\x1b[0m   Kernel: 6.8",
+            "          Shell: fish",
+        ]
+    );
+}
+
+#[test]
+fn logo_on_the_right() {
+    let logo: Logo = parse_logo("test", "ab\ncdef\ngh\nij").unwrap();
+    let lines: Vec<String> = arrange(&logo, &infos()[..2], LogoPosition::Right, 1);
+    assert_eq!(
+        lines,
+        vec![
+            " \x1b[1mOS:\x1b[0m Void    \x1b[38;5;6mab\x1b[0m  ",
+            " Kernel: 6.8 \x1b[38;5;6mcdef\x1b[0m",
+            "             \x1b[38;5;6mgh\x1b[0m  ",
+            "             \x1b[38;5;6mij\x1b[0m  ",
+        ]
+    );
+}
+
+#[test]
+fn logo_above_and_below() {
+    let logo: Logo = parse_logo("test", "ab").unwrap();
+    let infos: Vec<String> = infos()[1..].to_vec();
+    assert_eq!(
+        arrange(&logo, &infos, LogoPosition::Top, 2),
+        vec![
+            "  \x1b[38;5;6mab\x1b[0m",
+            "",
+            "  Kernel: 6.8",
+            "  Shell: fish"
+        ]
+    );
+    assert_eq!(
+        arrange(&logo, &infos, LogoPosition::Bottom, 0),
+        vec!["Kernel: 6.8", "Shell: fish", "", "\x1b[38;5;6mab\x1b[0m"]
+    );
+}
+
+#[test]
+fn logo_size_follows_the_position() {
+    let logo: Logo = builtin_logo("archlinux").unwrap();
+    let infos: Vec<String> = vec!["OS: Arch Linux".to_owned(); 10];
+    let fit = |position: LogoPosition, terminal: (usize, usize)| {
+        logo.clone()
+            .fit(
+                &logo_config(LogoSize::Auto, position),
+                Some(terminal),
+                &infos,
+            )
+            .map(|logo| logo.widths().len())
+    };
+    // Stacked, the large logo fits in a narrow but high terminal.
+    assert_eq!(fit(LogoPosition::Top, (50, 40)), Some(21));
+    assert_eq!(fit(LogoPosition::Bottom, (50, 30)), Some(11));
+    assert_eq!(fit(LogoPosition::Right, (50, 40)), Some(11));
+    assert_eq!(fit(LogoPosition::Right, (40, 40)), None);
+}
+
+#[test]
+fn logo_position_in_the_configuration() {
+    let config: Config = serde_yaml::from_str("logo:\n  position: below\n  padding: 1\n").unwrap();
+    assert_eq!(config.logo.position, LogoPosition::Bottom);
+    assert_eq!(config.logo.padding, 1);
+    assert_eq!(Config::default().logo.position, LogoPosition::Left);
+    assert_eq!(Config::default().logo.padding, 3);
+    assert!(serde_yaml::from_str::<Config>("logo:\n  position: center\n").is_err());
+    assert!(serde_yaml::from_str::<Config>("logo:\n  padding: -1\n").is_err());
+}
+
+#[test]
+fn logo_origin_follows_the_position() {
+    let infos: Vec<String> = infos();
+    assert_eq!(logo_origin(&infos, LogoPosition::Left, 3), (0, 3));
+    assert_eq!(logo_origin(&infos, LogoPosition::Right, 1), (0, 13));
+    assert_eq!(logo_origin(&infos, LogoPosition::Top, 2), (0, 2));
+    assert_eq!(logo_origin(&infos, LogoPosition::Bottom, 2), (4, 2));
+}
+
+#[test]
+fn logo_list_with_files() {
+    let entries: Vec<LogoEntry> = list_logos_in(&dirs());
+    let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+    let mut sorted: Vec<&str> = names.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(names, sorted);
+    // The files that do not replace a builtin logo are listed as well.
+    assert_eq!(entries.len(), LOGOS.len() + 3);
+    assert!(names.contains(&"mydistro") && names.contains(&"void"));
+
+    let entry = |name: &str| entries.iter().find(|entry| entry.name == name).unwrap();
+    assert!(entry("archlinux")
+        .path
+        .as_ref()
+        .unwrap()
+        .ends_with("user/archlinux.txt"));
+    assert_eq!(entry("manjaro").aliases, vec!["manjarolinux"]);
+    assert!(entry("manjaro")
+        .to_string()
+        .starts_with("manjaro (manjarolinux): "));
+    assert!(entry("mydistro").aliases.is_empty());
+    assert!(entry("mydistro")
+        .path
+        .as_ref()
+        .unwrap()
+        .ends_with("user/My Distro.txt"));
+    assert_eq!(entry("ubuntu").path, None);
+    assert_eq!(entry("ubuntu").to_string(), "ubuntu");
+}
+
+#[test]
+fn preview_shows_the_small_variant() {
+    let logo: Logo = builtin_logo("ubuntu").unwrap();
+    let large: Vec<String> = logo.lines();
+    let small: Vec<String> = logo.clone().to_small().lines();
+    let lines: Vec<String> = preview(&logo);
+    assert_eq!(lines[..large.len()], large[..]);
+    assert_eq!(lines[large.len()], "");
+    assert_eq!(lines[large.len() + 1..], small[..]);
+
+    let file: Logo = get_logo_in("void", &dirs()).unwrap().unwrap();
+    assert_eq!(preview(&file), file.lines());
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn logo_folders_follow_the_environment() {
+    let environment = |vars: &[(&str, &str)]| {
+        Environment::new(
+            "/mnt",
+            vars.iter()
+                .map(|(name, value)| ((*name).to_owned(), (*value).to_owned()))
+                .collect::<HashMap<String, String>>(),
+        )
+    };
+    let paths = |paths: &[&str]| paths.iter().map(PathBuf::from).collect::<Vec<PathBuf>>();
+
+    assert_eq!(
+        logo_dirs(&environment(&[("HOME", "/home/user")]), None),
+        paths(&[
+            "/mnt/home/user/.config/afetch/logos",
+            "/mnt/etc/afetch/logos"
+        ])
+    );
+    assert_eq!(
+        logo_dirs(
+            &environment(&[("HOME", "/home/user"), ("XDG_CONFIG_HOME", "/config")]),
+            None
+        ),
+        paths(&["/mnt/config/afetch/logos", "/mnt/etc/afetch/logos"])
+    );
+    // The folder next to the `--config` file comes first, and is only listed once.
+    assert_eq!(
+        logo_dirs(
+            &environment(&[("HOME", "/home/user")]),
+            Some(Path::new("/srv/afetch/work.yaml"))
+        ),
+        paths(&[
+            "/srv/afetch/logos",
+            "/mnt/home/user/.config/afetch/logos",
+            "/mnt/etc/afetch/logos"
+        ])
+    );
+    assert_eq!(
+        logo_dirs(
+            &environment(&[("HOME", "/home/user")]),
+            Some(Path::new("/mnt/etc/afetch/config.yaml"))
+        ),
+        paths(&[
+            "/mnt/etc/afetch/logos",
+            "/mnt/home/user/.config/afetch/logos"
+        ])
+    );
+}
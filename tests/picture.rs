@@ -0,0 +1,194 @@
+#![cfg(feature = "image")]
+
+use afetch::config::PictureProtocol;
+use afetch::logos::graphics::{encode, graphics_logo, GraphicsProtocol};
+use afetch::logos::picture::{picture_to_logo, render_picture, scale_picture, PictureCharacters};
+use afetch::logos::{Logo, LogoColor};
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::path::PathBuf;
+
+/// A red square on the left half of a transparent picture, `width` by `height` pixels.
+fn half_square(width: u32, height: u32) -> DynamicImage {
+    DynamicImage::ImageRgba8(RgbaImage::from_fn(width, height, |x, _| {
+        if x < width / 2 {
+            Rgba([255, 0, 0, 255])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    }))
+}
+
+fn text(logo: &Logo) -> Vec<String> {
+    logo.lines()
+        .iter()
+        .map(|line| String::from_utf8_lossy(&strip_ansi_escapes::strip(line)).into_owned())
+        .collect()
+}
+
+#[test]
+fn braille_keeps_the_aspect_ratio() {
+    let logo: Logo = picture_to_logo(&half_square(8, 8), PictureCharacters::Braille, 4, None);
+    // 8 by 8 dots, so 4 columns and 2 rows.
+    assert_eq!(text(&logo), vec!["⣿⣿⠀⠀", "⣿⣿⠀⠀"]);
+    assert_eq!(logo.color(), LogoColor::Rgb(255, 0, 0));
+    assert_eq!(
+        logo.lines()[0],
+        "\x1b[38;2;255;0;0m\x1b[38;2;255;0;0m⣿⣿⠀⠀\x1b[0m"
+    );
+}
+
+#[test]
+fn half_blocks_set_both_colors() {
+    let picture: DynamicImage =
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(2, 4, |x, y| match (x, y) {
+            (0, 0 | 1) => Rgba([0, 0, 255, 255]),
+            (1, 0) => Rgba([0, 255, 0, 255]),
+            (1, 1) => Rgba([0, 0, 255, 255]),
+            (0, 3) => Rgba([255, 255, 255, 255]),
+            _ => Rgba([0, 0, 0, 0]),
+        }));
+    let logo: Logo = picture_to_logo(&picture, PictureCharacters::HalfBlocks, 2, None);
+    assert_eq!(text(&logo), vec!["▀▀", "▄ "]);
+    assert_eq!(
+        logo.art,
+        "\x1b[38;2;0;0;255m\x1b[48;2;0;0;255m▀\x1b[38;2;0;255;0m\x1b[48;2;0;0;255m▀\n\
+         \x1b[0m\x1b[38;2;255;255;255m▄\x1b[0m "
+    );
+    assert_eq!(logo.widths(), vec![2, 2]);
+}
+
+#[test]
+fn opaque_pictures_are_dithered_by_brightness() {
+    let grey: DynamicImage =
+        DynamicImage::ImageRgba8(RgbaImage::from_pixel(16, 16, Rgba([128, 128, 128, 255])));
+    let logo: Logo = picture_to_logo(&grey, PictureCharacters::Braille, 8, None);
+    let dots: u32 = text(&logo)
+        .concat()
+        .chars()
+        .map(|c| (c as u32 - 0x2800).count_ones())
+        .sum();
+    // About half of the 16 by 16 dots are raised.
+    assert!((112..=144).contains(&dots), "{}", dots);
+}
+
+#[test]
+fn picture_files() {
+    let path: PathBuf = std::env::temp_dir().join("afetch-picture-test.png");
+    half_square(20, 10).save(&path).unwrap();
+    let logo: Logo = render_picture(&path, PictureCharacters::HalfBlocks, 10, None).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(logo.name, "afetch-picture-test");
+    assert_eq!(logo.path, Some(path));
+    assert_eq!(text(&logo), vec!["▀▀▀▀▀     "; 3]);
+
+    let error: String = render_picture(
+        &std::env::temp_dir().join("afetch-missing.png"),
+        PictureCharacters::Braille,
+        10,
+        None,
+    )
+    .unwrap_err();
+    assert!(error.contains("afetch-missing.png"), "{}", error);
+}
+
+#[test]
+fn pictures_fit_in_the_height() {
+    let logo: Logo = picture_to_logo(&half_square(8, 16), PictureCharacters::Braille, 4, Some(2));
+    assert_eq!(text(&logo), vec!["⣿⠀", "⣿⠀"]);
+    assert_eq!(scale_picture((200, 100), (10, 20), 10, None), (100, 50));
+    assert_eq!(scale_picture((200, 100), (10, 20), 10, Some(2)), (80, 40));
+    assert_eq!(scale_picture((1, 1000), (10, 20), 10, Some(1)), (1, 20));
+}
+
+#[test]
+fn graphics_protocol_from_the_environment() {
+    let select = |protocol: PictureProtocol, vars: &[(&str, &str)]| {
+        GraphicsProtocol::select(protocol, |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| (*value).to_owned())
+        })
+    };
+    assert_eq!(
+        select(PictureProtocol::Auto, &[("TERM", "xterm-kitty")]),
+        Some(GraphicsProtocol::Kitty)
+    );
+    assert_eq!(
+        select(PictureProtocol::Auto, &[("TERM_PROGRAM", "iTerm.app")]),
+        Some(GraphicsProtocol::Iterm)
+    );
+    assert_eq!(
+        select(PictureProtocol::Auto, &[("TERM", "foot")]),
+        Some(GraphicsProtocol::Sixel)
+    );
+    assert_eq!(
+        select(PictureProtocol::Auto, &[("TERM", "xterm-256color")]),
+        None
+    );
+    assert_eq!(
+        select(PictureProtocol::Sixel, &[("TERM", "xterm-kitty")]),
+        Some(GraphicsProtocol::Sixel)
+    );
+    assert_eq!(
+        select(PictureProtocol::Blocks, &[("TERM", "xterm-kitty")]),
+        None
+    );
+}
+
+#[test]
+fn sixel_escape() {
+    // A red pixel above a transparent one, next to two blue pixels.
+    let pixels: RgbaImage = RgbaImage::from_fn(2, 2, |x, y| match (x, y) {
+        (0, 0) => Rgba([255, 0, 0, 255]),
+        (1, _) => Rgba([0, 0, 255, 255]),
+        _ => Rgba([0, 0, 0, 0]),
+    });
+    assert_eq!(
+        encode(&pixels, GraphicsProtocol::Sixel, 1, 1).unwrap(),
+        "\x1bP0;1;0q\"1;1;2;2#5;2;0;0;100#180;2;100;0;0#5?B$#180@?-\x1b\\"
+    );
+}
+
+#[test]
+fn kitty_and_iterm_escapes() {
+    let pixels: RgbaImage = RgbaImage::from_fn(64, 64, |x, y| {
+        Rgba([(x * 7 + y * 13) as u8, (x * y) as u8, (x ^ y) as u8, 255])
+    });
+    let iterm: String = encode(&pixels, GraphicsProtocol::Iterm, 3, 2).unwrap();
+    assert!(
+        iterm.starts_with("\x1b]1337;File=inline=1;size="),
+        "{}",
+        iterm
+    );
+    // The data starts with the signature of PNG files.
+    assert!(
+        iterm.contains(";width=3;height=2;preserveAspectRatio=0:iVBORw0KGgo"),
+        "{}",
+        iterm
+    );
+    assert!(iterm.ends_with('\x07'));
+
+    let kitty: String = encode(&pixels, GraphicsProtocol::Kitty, 3, 2).unwrap();
+    let chunks: Vec<&str> = kitty.split_terminator("\x1b\\").collect();
+    assert!(chunks.len() > 1, "{}", kitty);
+    assert!(chunks[0].starts_with("\x1b_Ga=T,f=100,q=2,c=3,r=2,m=1;iVBORw0KGgo"));
+    assert!(chunks[1..chunks.len() - 1]
+        .iter()
+        .all(|chunk| chunk.starts_with("\x1b_Gm=1;")));
+    assert!(chunks[chunks.len() - 1].starts_with("\x1b_Gm=0;"));
+}
+
+#[test]
+fn graphics_logo_is_blank() {
+    let path: PathBuf = std::env::temp_dir().join("afetch-graphics-test.png");
+    half_square(40, 20).save(&path).unwrap();
+    let logo: Logo = graphics_logo(&path, GraphicsProtocol::Kitty, (10, 20), 4, None).unwrap();
+    std::fs::remove_file(&path).ok();
+    // 40 by 20 pixels, so 4 columns and 1 row.
+    assert_eq!(logo.art, "    ");
+    assert_eq!(logo.color(), LogoColor::Rgb(255, 0, 0));
+    assert!(logo
+        .graphic
+        .unwrap()
+        .starts_with("\x1b_Ga=T,f=100,q=2,c=4,r=1,m=0;"));
+}
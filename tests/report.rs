@@ -0,0 +1,163 @@
+use afetch::config::{EntryId, LayoutItem};
+use afetch::render::Renderer;
+use afetch::system::report::{
+    DiskInfo, DiskUsageInfo, EntryValue, FetchReport, NetworkInfo, PackageManagerInfo, ReportEntry,
+};
+use afetch::translations::{Translation, FALLBACK_CODE};
+use afetch_colored::{AnsiOrCustom, CustomColor};
+use serde_json::{json, Value};
+
+fn entry(id: &str, value: EntryValue) -> ReportEntry {
+    ReportEntry {
+        id: id.to_owned(),
+        label_key: format!("label-{}", id),
+        value,
+    }
+}
+
+fn package_manager(name: &str, count: usize) -> PackageManagerInfo {
+    PackageManagerInfo {
+        name: name.to_owned(),
+        count,
+    }
+}
+
+#[test]
+fn packages_are_summed_by_manager() {
+    let report: FetchReport = FetchReport {
+        entries: vec![entry(
+            "packages",
+            EntryValue::Packages(vec![
+                package_manager("pkg", 3),
+                package_manager("snap", 2),
+                package_manager("pkg", 5),
+                package_manager("snap", 1),
+            ]),
+        )],
+        ..FetchReport::default()
+    };
+    let json: Value = serde_json::to_value(&report).unwrap();
+    assert_eq!(json["packages"], json!({ "pkg": 8, "snap": 3 }));
+}
+
+#[test]
+fn json_keys_do_not_depend_on_the_configuration() {
+    let report: FetchReport = FetchReport {
+        username: "user".to_owned(),
+        hostname: "host".to_owned(),
+        entries: vec![
+            entry("os", EntryValue::Text("Arch Linux".to_owned())),
+            entry("gpu", EntryValue::Gpus(vec!["Intel UHD 620".to_owned()])),
+            entry("vpn", EntryValue::Text("work".to_owned())),
+        ],
+    };
+    let json: Value = serde_json::to_value(&report).unwrap();
+    let keys: Vec<&str> = json
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    let mut expected: Vec<&str> = vec![
+        "username",
+        "hostname",
+        "os",
+        "host",
+        "kernel",
+        "uptime",
+        "packages",
+        "shell",
+        "resolution",
+        "desktop",
+        "wm",
+        "terminal",
+        "terminal_font",
+        "cpu",
+        "gpus",
+        "memory",
+        "network",
+        "disks",
+        "public_ip",
+        "battery",
+        "custom",
+    ];
+    expected.sort();
+    assert_eq!(keys, expected);
+    assert_eq!(json["os"], "Arch Linux");
+    assert_eq!(json["gpus"], json!(["Intel UHD 620"]));
+    assert_eq!(json["kernel"], Value::Null);
+    assert_eq!(json["custom"], json!({ "vpn": "work" }));
+}
+
+#[test]
+fn disks_are_listed_under_disks() {
+    let report: FetchReport = FetchReport {
+        entries: vec![
+            entry(
+                "disk",
+                EntryValue::Disks(vec![DiskInfo {
+                    mount_point: "/".to_owned(),
+                    used: 1,
+                    total: 4,
+                }]),
+            ),
+            entry(
+                "disks",
+                EntryValue::DiskUsage(DiskUsageInfo { used: 1, total: 4 }),
+            ),
+        ],
+        ..FetchReport::default()
+    };
+    let json: Value = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        json["disks"],
+        json!([{ "mount_point": "/", "used": 1, "total": 4 }])
+    );
+    assert_eq!(json.get("disk"), None);
+    assert_eq!(json["custom"], json!({}));
+}
+
+#[test]
+fn separator_and_arrows_fall_back_to_ascii() {
+    let report: FetchReport = FetchReport {
+        username: "user".to_owned(),
+        hostname: "host".to_owned(),
+        entries: vec![entry(
+            "network",
+            EntryValue::Network(NetworkInfo {
+                sent: 2048,
+                received: 1024,
+            }),
+        )],
+    };
+    let layout: Vec<LayoutItem> = vec![LayoutItem::Separator, LayoutItem::Entry(EntryId::Network)];
+    let language: Translation = Translation::load_from(FALLBACK_CODE, None).unwrap();
+    let render = |ascii_only: bool| {
+        Renderer::new(
+            AnsiOrCustom::Ansi(6),
+            CustomColor::new(255, 255, 255),
+            &language,
+        )
+        .with_ascii_only(ascii_only)
+        .render(&report, &layout)
+        .iter()
+        .map(|line| String::from_utf8_lossy(&strip_ansi_escapes::strip(line)).into_owned())
+        .collect::<Vec<String>>()
+    };
+
+    let unicode: Vec<String> = render(false);
+    assert_eq!(unicode[0], "─────────");
+    assert!(
+        unicode[1].contains('↘') && unicode[1].contains('↗'),
+        "{}",
+        unicode[1]
+    );
+    let ascii: Vec<String> = render(true);
+    assert_eq!(ascii[0], "---------");
+    assert!(ascii[1].is_ascii(), "{}", ascii[1]);
+    assert!(
+        ascii[1].contains(" v ") && ascii[1].ends_with(" ^"),
+        "{}",
+        ascii[1]
+    );
+}
@@ -0,0 +1,98 @@
+use afetch::render::Renderer;
+use afetch::system::report::{EntryValue, MemoryInfo, ReportEntry};
+use afetch::template::{Template, TemplateError};
+use afetch::translations::{Translation, FALLBACK_CODE};
+use afetch_colored::{AnsiOrCustom, CustomColor};
+use std::collections::HashMap;
+
+fn render(template: &str, values: &[(&'static str, &str)]) -> String {
+    let values: HashMap<&str, String> = values
+        .iter()
+        .map(|(name, value)| (*name, (*value).to_owned()))
+        .collect();
+    Template::parse(template).unwrap().render(&values)
+}
+
+#[test]
+fn placeholders_are_replaced() {
+    assert_eq!(
+        render("{used} / {total}", &[("used", "2 GiB"), ("total", "8 GiB")]),
+        "2 GiB / 8 GiB"
+    );
+    // The spaces around a name are ignored, and a placeholder may be used twice.
+    assert_eq!(render("{ value }-{value}", &[("value", "a")]), "a-a");
+    assert_eq!(render("plain text", &[]), "plain text");
+}
+
+#[test]
+fn placeholders_without_value_are_empty() {
+    assert_eq!(render("[{version}]", &[]), "[]");
+}
+
+#[test]
+fn doubled_braces_are_literal() {
+    assert_eq!(render("{{{value}}}", &[("value", "x")]), "{x}");
+    assert_eq!(render("}}{{", &[]), "}{");
+    let template: Template = Template::parse("{{value}}").unwrap();
+    assert_eq!(template.placeholders().count(), 0);
+}
+
+#[test]
+fn invalid_templates() {
+    assert_eq!(
+        Template::parse("{used"),
+        Err(TemplateError::UnclosedPlaceholder)
+    );
+    assert_eq!(
+        Template::parse("used}"),
+        Err(TemplateError::UnexpectedClosingBrace)
+    );
+    assert_eq!(Template::parse("{ }"), Err(TemplateError::EmptyPlaceholder));
+}
+
+#[test]
+fn unknown_placeholders_are_listed() {
+    let error: TemplateError =
+        Template::parse_with_placeholders("{used} of {totl}", &["value", "used", "total"])
+            .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown placeholder `{totl}`, available placeholders are: {value}, {used}, {total}"
+    );
+    assert!(Template::parse_with_placeholders("{used}", &["value", "used"]).is_ok());
+}
+
+#[test]
+fn formats_are_applied_to_entries() {
+    let language: Translation = Translation::load_from(FALLBACK_CODE, None).unwrap();
+    let formats: HashMap<String, Template> = HashMap::from([(
+        "memory".to_owned(),
+        Template::parse("{used} of {total} ({percent}%)").unwrap(),
+    )]);
+    let renderer: Renderer = Renderer::new(
+        AnsiOrCustom::Ansi(6),
+        CustomColor::new(255, 255, 255),
+        &language,
+    )
+    .with_formats(formats);
+    let entry = |id: &str| ReportEntry {
+        id: id.to_owned(),
+        label_key: "label-memory".to_owned(),
+        value: EntryValue::Memory(MemoryInfo {
+            used: 1024 * 1024 * 1024,
+            total: 4 * 1024 * 1024 * 1024,
+        }),
+    };
+    let text = |lines: Vec<String>| {
+        lines
+            .iter()
+            .map(|line| String::from_utf8_lossy(&strip_ansi_escapes::strip(line)).into_owned())
+            .collect::<Vec<String>>()
+    };
+
+    let formatted: Vec<String> = text(renderer.render_entry(&entry("memory")));
+    assert_eq!(formatted, vec!["Memory: 1GB of 4GB (25%)"]);
+    // Entries without a format keep the default layout.
+    let default: Vec<String> = text(renderer.render_entry(&entry("other")));
+    assert_eq!(default, vec!["Memory: 1GB/4GB"]);
+}
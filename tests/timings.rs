@@ -0,0 +1,83 @@
+use afetch::timings::{self, render, Timing, TimingKind};
+use std::time::Duration;
+
+fn timing(kind: TimingKind, name: &str, ms: u64, note: Option<&'static str>) -> Timing {
+    Timing {
+        kind,
+        name: name.to_owned(),
+        duration: Duration::from_millis(ms),
+        note,
+    }
+}
+
+#[test]
+fn timings_are_rendered_as_a_table() {
+    let timings: Vec<Timing> = vec![
+        timing(TimingKind::Step, "entries", 80, None),
+        timing(TimingKind::Entry, "packages", 75, None),
+        timing(TimingKind::Entry, "gpu", 0, Some("cached")),
+        timing(TimingKind::Command, "pacman -Qq", 40, Some("killed")),
+    ];
+    let output: String = render(&timings, Duration::from_millis(100));
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines[0], "Timings (total: 100.0 ms):");
+    assert_eq!(
+        lines[1..],
+        [
+            format!("  step     {:<48}    80.0 ms  ◀", "entries"),
+            format!("  entry    {:<48}    75.0 ms  ◀", "packages"),
+            format!("  entry    {:<48}     0.0 ms (cached)", "gpu"),
+            format!("  command  {:<48}    40.0 ms (killed)", "pacman -Qq"),
+        ]
+    );
+}
+
+#[test]
+fn long_names_are_shortened() {
+    let name: String = "x".repeat(60);
+    let output: String = render(
+        &[timing(TimingKind::Command, &name, 1, None)],
+        Duration::from_millis(10),
+    );
+    let line: &str = output.lines().nth(1).unwrap();
+    assert!(line.contains(&format!(" {}… ", "x".repeat(47))), "{}", line);
+    assert!(!line.contains(&"x".repeat(48)));
+}
+
+#[test]
+fn steps_come_first_then_the_slowest_timings() {
+    assert!(!timings::is_enabled());
+    timings::record(TimingKind::Step, "ignored", Duration::from_millis(1), None);
+    timings::enable();
+    assert!(timings::is_enabled());
+    timings::record(
+        TimingKind::Command,
+        "uname -r",
+        Duration::from_millis(2),
+        None,
+    );
+    timings::record(
+        TimingKind::Step,
+        "configuration",
+        Duration::from_millis(1),
+        None,
+    );
+    timings::record(TimingKind::Entry, "os", Duration::from_millis(1), None);
+    timings::record(
+        TimingKind::Entry,
+        "packages",
+        Duration::from_millis(9),
+        None,
+    );
+    timings::record(TimingKind::Step, "entries", Duration::from_millis(9), None);
+
+    let names: Vec<String> = timings::take()
+        .into_iter()
+        .map(|timing| timing.name)
+        .collect();
+    assert_eq!(
+        names,
+        ["configuration", "entries", "packages", "os", "uname -r"]
+    );
+    assert!(!timings::is_enabled());
+}
@@ -0,0 +1,163 @@
+use afetch::translations::plural::{PluralCategory, PluralRules};
+use afetch::translations::{language_codes, Translation, FALLBACK_CODE};
+use afetch::utils::{convert_to_readable_unity, format_time};
+use std::path::PathBuf;
+
+fn user_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("lang")
+}
+
+fn translation(code: &str) -> Translation {
+    Translation::load_from(code, Some(&user_dir())).unwrap()
+}
+
+#[test]
+fn bundled_translations_are_complete() {
+    for code in language_codes(None) {
+        let translation: Translation = Translation::load_from(&code, None).unwrap();
+        assert_eq!(translation.missing_keys(), Vec::<String>::new(), "{}", code);
+        assert_eq!(translation.unknown_keys(), Vec::<&str>::new(), "{}", code);
+    }
+}
+
+#[test]
+fn english_defines_the_labels() {
+    let translation: Translation = Translation::load_from(FALLBACK_CODE, None).unwrap();
+    assert_eq!(translation.get("label-os"), "OS: ");
+    assert_eq!(translation.plural("days", 2), "2 days");
+}
+
+#[test]
+fn user_file_overrides_bundled_keys() {
+    let translation: Translation = translation("fr");
+    assert_eq!(translation.get("label-os"), "Système : ");
+    assert_eq!(translation.get("label-host"), "Hôte : ");
+    assert!(translation.missing_keys().is_empty());
+}
+
+#[test]
+fn missing_keys_fall_back_to_english() {
+    let translation: Translation = translation("de");
+    assert_eq!(translation.plural("days", 3), "3 Tage");
+    assert_eq!(translation.get("label-os"), "OS: ");
+    assert!(translation.missing_keys().contains(&"label-os".to_owned()));
+    assert!(!translation
+        .missing_keys()
+        .contains(&"label-host".to_owned()));
+    assert_eq!(translation.unknown_keys(), vec!["label-hots"]);
+}
+
+#[test]
+fn unknown_key_is_returned_as_is() {
+    let translation: Translation = Translation::load_from("fr", None).unwrap();
+    assert_eq!(translation.get("label-nothing"), "label-nothing");
+}
+
+#[test]
+fn invalid_user_file() {
+    let error: String = Translation::load_from("broken", Some(&user_dir())).unwrap_err();
+    assert!(error.contains("broken.yaml"), "{}", error);
+}
+
+#[test]
+fn invalid_plural_rule() {
+    let error: String = Translation::load_from("invalid-rule", Some(&user_dir())).unwrap_err();
+    assert!(error.contains("not a valid modulo"), "{}", error);
+}
+
+#[test]
+fn user_translations_are_listed() {
+    assert_eq!(language_codes(None), vec!["en", "fr"]);
+    assert_eq!(
+        language_codes(Some(&user_dir())),
+        vec!["ar", "broken", "de", "en", "fr", "invalid-rule", "pl"]
+    );
+}
+
+#[test]
+fn english_and_french_plurals() {
+    let english: Translation = Translation::load_from("en", None).unwrap();
+    let french: Translation = Translation::load_from("fr", None).unwrap();
+    assert_eq!(english.plural("hours", 0), "0 hours");
+    assert_eq!(english.plural("hours", 1), "1 hour");
+    assert_eq!(french.plural("hours", 0), "0 heure");
+    assert_eq!(french.plural("hours", 1), "1 heure");
+    assert_eq!(french.plural("hours", 2), "2 heures");
+}
+
+#[test]
+fn polish_plurals() {
+    let polish: Translation = translation("pl");
+    assert_eq!(polish.plural("hours", 1), "1 godzina");
+    assert_eq!(polish.plural("hours", 3), "3 godziny");
+    assert_eq!(polish.plural("hours", 5), "5 godzin");
+    assert_eq!(polish.plural("hours", 12), "12 godzin");
+    assert_eq!(polish.plural("hours", 22), "22 godziny");
+    // The forms that are not defined use `other`.
+    assert_eq!(polish.plural("minutes", 5), "5 minuty");
+}
+
+#[test]
+fn arabic_plurals() {
+    let arabic: Translation = translation("ar");
+    assert_eq!(arabic.plural("days", 0), "0 يوم");
+    assert_eq!(arabic.plural("days", 1), "يوم واحد");
+    assert_eq!(arabic.plural("days", 2), "يومان");
+    assert_eq!(arabic.plural("days", 7), "7 أيام");
+    assert_eq!(arabic.plural("days", 11), "11 يومًا");
+    assert_eq!(arabic.plural("days", 100), "100 يوم");
+}
+
+#[test]
+fn missing_plural_forms() {
+    let polish: Translation = translation("pl");
+    let missing_keys: Vec<String> = polish.missing_keys();
+    assert!(missing_keys.contains(&"minutes.few".to_owned()));
+    assert!(missing_keys.contains(&"minutes.many".to_owned()));
+    assert!(!missing_keys.contains(&"hours.few".to_owned()));
+    assert!(missing_keys.contains(&"seconds".to_owned()));
+}
+
+#[test]
+fn plural_rules_syntax() {
+    let rules: PluralRules = PluralRules::parse([
+        (PluralCategory::One, "n is 1"),
+        (PluralCategory::Two, "n is not 1 and n within 2..3"),
+        (PluralCategory::Few, "n not in 0..3,7 and n % 10 in 4..6"),
+    ])
+    .unwrap();
+    assert_eq!(rules.category(1), PluralCategory::One);
+    assert_eq!(rules.category(3), PluralCategory::Two);
+    assert_eq!(rules.category(15), PluralCategory::Few);
+    assert_eq!(rules.category(7), PluralCategory::Other);
+    assert!(PluralRules::parse([(PluralCategory::One, "x = 1")]).is_err());
+    assert!(PluralRules::parse([(PluralCategory::One, "n = 3..1")]).is_err());
+}
+
+#[test]
+fn localized_uptime() {
+    let english: Translation = Translation::load_from("en", None).unwrap();
+    let arabic: Translation = translation("ar");
+    assert_eq!(format_time(30, &english), "30 seconds");
+    assert_eq!(format_time(90_061, &english), "1 day, 1 hour, 1 minute");
+    assert_eq!(format_time(2 * 86_400 + 7_200, &arabic), "يومان، 2 hours");
+}
+
+#[test]
+fn localized_sizes() {
+    let english: Translation = Translation::load_from("en", None).unwrap();
+    let french: Translation = Translation::load_from("fr", None).unwrap();
+    assert_eq!(
+        convert_to_readable_unity(1_610_612_736_f64, &english),
+        "1.5GB"
+    );
+    assert_eq!(
+        convert_to_readable_unity(1_610_612_736_f64, &french),
+        "1,5Go"
+    );
+    assert_eq!(convert_to_readable_unity(2048_f64, &french), "2ko");
+    assert_eq!(convert_to_readable_unity(0_f64, &english), "0 B");
+}